@@ -0,0 +1,46 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use hierro2d::{
+    component::{Component, IntoComponent, Rect},
+    Animation, AnimationId, Application, Easing, Timeline, Tween,
+};
+
+/// a click starts and stops the square breathing, a right click makes it blink twice
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let breathing = Rc::new(Cell::new(None::<AnimationId>));
+        Rect::new()
+            .size(0.2, 0.2)
+            .center()
+            .color(0.3, 0.6, 1., 1.)
+            .on_click(move |mut ctx, _| {
+                if let Some(animation) = breathing.take() {
+                    ctx.cancel_animation(animation);
+                    return;
+                }
+                let id = ctx.current_id().unwrap();
+                let breathe = Tween::radius(0.2)
+                    .duration(Duration::from_millis(800))
+                    .easing(Easing::EaseInOut);
+                let animation = Animation::new(breathe).forever().alternate();
+                breathing.set(Some(ctx.animate(id, animation)));
+            })
+            .on_right_click(|mut ctx, _| {
+                let id = ctx.current_id().unwrap();
+                let blink = Timeline::sequence([Tween::opacity(0.2), Tween::opacity(1.)]);
+                ctx.animate(id, Animation::new(blink).repeat(2));
+            })
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("animation");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
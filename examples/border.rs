@@ -0,0 +1,47 @@
+use hierro2d::{
+    component::{BorderAlign, Container, IntoComponent, Rect},
+    Application,
+};
+
+/// the same frame with its border inside, across and around its edge, and a dashed card with
+/// two rounded corners
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let frame = |index: usize, align| {
+            Rect::new()
+                .position(0.1 + index as f32 * 0.3, 0.2)
+                .size(0.2, 0.25)
+                .radius(0.05)
+                .color(0.2, 0.2, 0.25, 1.)
+                .border(8.)
+                .border_color(0.4, 0.8, 1., 0.8)
+                .border_align(align)
+        };
+        let card = Rect::new()
+            .position(0.35, 0.6)
+            .size(0.3, 0.2)
+            .corner_radii(0.1, 0., 0.1, 0.)
+            .color(0.3, 0.3, 0.4, 1.)
+            .border(4.)
+            .border_color(1., 0.8, 0.3, 1.)
+            .border_dashes(12., 6.);
+        Rect::new()
+            .size(1., 1.)
+            .with_child(frame(0, BorderAlign::Inner))
+            .with_child(frame(1, BorderAlign::Center))
+            .with_child(frame(2, BorderAlign::Outer))
+            .with_child(card)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("border");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,42 @@
+use hierro2d::{
+    component::{Camera, Component, Container, IntoComponent, Rect},
+    Application,
+};
+
+/// a grid of tiles zoomed around the cursor with the wheel, or step by step with double clicks
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let camera = Camera::new()
+            .size(0.8, 0.8)
+            .center()
+            .zoom_range(0.5, 4.)
+            .on_double_click(|mut ctx, event| {
+                let id = ctx.current_id().unwrap();
+                let zoom = ctx.camera_zoom(id).unwrap();
+                let zoom = if zoom >= 4. { 1. } else { (zoom * 2.).min(4.) };
+                ctx.zoom_camera(id, zoom, event.position);
+            });
+        (0..16).fold(camera, |camera, index| {
+            let (column, row) = ((index % 4) as f32, (index / 4) as f32);
+            let shade = 0.2 + (column + row) * 0.08;
+            camera.with_child(
+                Rect::new()
+                    .position(column * 0.25 + 0.02, row * 0.25 + 0.02)
+                    .size(0.21, 0.21)
+                    .color(0.2, shade, 0.3, 1.),
+            )
+        })
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("camera");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,37 @@
+use hierro2d::{
+    component::{Component, Container, Img, IntoComponent, Overflow, Rect},
+    Application,
+};
+
+const BADGE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/badge.png");
+
+/// a rounded frame cutting off the part of the picture that does not fit inside it
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Rect::new()
+            .size(0.4, 0.4)
+            .center()
+            .radius(0.1)
+            .color(0.2, 0.2, 0.25, 1.)
+            .overflow(Overflow::Clip)
+            .with_child(
+                Img::new()
+                    .content(BADGE)
+                    .unwrap()
+                    .position(0.25, 0.25)
+                    .size(1., 1.),
+            )
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("clip");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
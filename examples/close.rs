@@ -0,0 +1,45 @@
+use std::{cell::Cell, rc::Rc};
+
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect, Text},
+    Application, Context,
+};
+
+/// the window stays open the first time it is asked to close, clicking the button quits
+/// without asking
+#[derive(Default)]
+struct App {
+    asked: Rc<Cell<bool>>,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Rect::new()
+            .size(0.3, 0.1)
+            .center()
+            .color(0.6, 0.2, 0.2, 1.)
+            .on_click(|mut ctx, _| ctx.exit())
+            .with_child(Text::new().content("Quit").size(1., 1.))
+    }
+
+    fn on_close_requested(&self) -> impl FnMut(Context) -> bool + 'static {
+        let asked = self.asked.clone();
+        move |_| {
+            if asked.replace(true) {
+                return true;
+            }
+            println!("close the window again to quit");
+            false
+        }
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("close");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App::default();
+    app.run().await;
+}
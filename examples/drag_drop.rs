@@ -0,0 +1,40 @@
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect},
+    Application,
+};
+
+/// cards dropped on the board stay where they were let go, cards dropped outside it spring
+/// back
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let card = |index: usize| {
+            let shade = 0.4 + index as f32 * 0.15;
+            Rect::new()
+                .position(0.1 + index as f32 * 0.3, 0.1)
+                .size(0.2, 0.3)
+                .radius(0.02)
+                .color(shade, 0.3, 0.5, 1.)
+                .draggable()
+        };
+        (0..3).fold(
+            Rect::new()
+                .size(0.8, 0.6)
+                .center()
+                .color(0.15, 0.15, 0.2, 1.)
+                .on_drop(|mut ctx, event| ctx.translate(event.source, event.offset)),
+            |board, index| board.with_child(card(index)),
+        )
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("drag and drop");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,34 @@
+use hierro2d::{
+    component::{Component, Img, IntoComponent},
+    Application,
+};
+
+const BADGE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/badge.png");
+
+/// drop an image file from the file manager on the picture to show it instead
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Img::new()
+            .content(BADGE)
+            .unwrap()
+            .size(0.5, 0.5)
+            .center()
+            .on_file_drop(|mut ctx, event| {
+                if let Err(err) = ctx.set_image(event.target, &event.paths[0]) {
+                    eprintln!("{err}");
+                }
+            })
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("file drop");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
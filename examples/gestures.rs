@@ -0,0 +1,49 @@
+use std::{cell::Cell, rc::Rc};
+
+use hierro2d::{
+    component::{Component, IntoComponent, Rect, Transform},
+    Application, Context, GestureEvent,
+};
+
+/// pinch, turn and move the card with two fingers on a touch screen
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let transform = Rc::new(Cell::new(Transform::new()));
+        let follow = |change: fn(Transform, &GestureEvent) -> Transform| {
+            let transform = transform.clone();
+            move |mut ctx: Context, event: &mut GestureEvent| {
+                transform.set(change(transform.get(), event));
+                let id = ctx.current_id().unwrap();
+                ctx.set_transform(id, transform.get());
+            }
+        };
+        Rect::new()
+            .size(0.3, 0.3)
+            .center()
+            .radius(0.05)
+            .color(0.3, 0.5, 0.8, 1.)
+            .on_pinch(follow(|transform, event| {
+                let (x, y) = transform.scale;
+                transform.scale(x * event.scale, y * event.scale)
+            }))
+            .on_rotate(follow(|transform, event| {
+                transform.rotate(transform.rotation + event.rotation)
+            }))
+            .on_pan(follow(|transform, event| {
+                let (x, y) = transform.translation;
+                transform.translate(x + event.translation.0, y + event.translation.1)
+            }))
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("gestures");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,52 @@
+use hierro2d::{
+    component::{Container, Ellipse, Fill, IntoComponent, Rect, Text},
+    Application,
+};
+
+/// linear, radial and conic gradients on a rect, text and a circle
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let title = Text::new()
+            .content("Hello hierro2d!")
+            .position(0.1, 0.1)
+            .size(0.8, 0.2)
+            .fill(
+                Fill::linear(0.)
+                    .stop(0., 1., 0.4, 0.4, 1.)
+                    .stop(0.5, 1., 1., 0.4, 1.)
+                    .stop(1., 0.4, 0.8, 1., 1.),
+            );
+        let card = Rect::new()
+            .position(0.1, 0.4)
+            .size(0.35, 0.4)
+            .radius(0.05)
+            .fill(
+                Fill::radial(0.3, 0.3)
+                    .stop(0., 0.5, 0.5, 0.7, 1.)
+                    .stop(1., 0.1, 0.1, 0.2, 1.),
+            );
+        let dial = Ellipse::circle().position(0.55, 0.4).size(0.35, 0.4).fill(
+            Fill::conic(0.5, 0.5, 0.)
+                .stop(0., 1., 0.8, 0.2, 1.)
+                .stop(0.5, 1., 0.4, 0.1, 1.)
+                .stop(1., 1., 0.8, 0.2, 1.),
+        );
+        Rect::new()
+            .size(1., 1.)
+            .with_child(title)
+            .with_child(card)
+            .with_child(dial)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("gradient");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,43 @@
+use hierro2d::{
+    component::{Container, IntoComponent, LineJoin, Path, Rect},
+    Application,
+};
+
+/// a star parsed from SVG path data and a dashed wave built from curves and an arc
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let star = Path::new()
+            .svg("M 50 0 L 61 35 L 98 35 L 68 57 L 79 91 L 50 70 L 21 91 L 32 57 L 2 35 L 39 35 Z")
+            .unwrap()
+            .position(0.1, 0.3)
+            .size(0.3, 0.4)
+            .color(1., 0.85, 0.3, 1.)
+            .stroke(3.)
+            .stroke_color(0.5, 0.3, 0., 1.)
+            .line_join(LineJoin::Round);
+        let wave = Path::new()
+            .move_to(0., 10.)
+            .cubic_to(10., 0., 20., 0., 30., 10.)
+            .quad_to(40., 20., 50., 10.)
+            .arc_to(10., 10., 0., false, true, 70., 10.)
+            .position(0.5, 0.4)
+            .size(0.4, 0.2)
+            .color(0., 0., 0., 0.)
+            .stroke(2.)
+            .stroke_color(0.9, 0.9, 0.9, 1.)
+            .dashes(&[8., 4.]);
+        Rect::new().size(1., 1.).with_child(star).with_child(wave)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("path");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,31 @@
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect, ScrollView},
+    Application,
+};
+
+/// a list taller than its view, scrolled with the wheel or by dragging the scrollbar
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        (0..20).fold(ScrollView::new().size(0.4, 0.6).center(), |list, index| {
+            let shade = 0.2 + index as f32 * 0.03;
+            list.with_child(
+                Rect::new()
+                    .position(0., index as f32 * 0.25)
+                    .size(1., 0.2)
+                    .color(shade, shade, shade, 1.),
+            )
+        })
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("scroll view");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
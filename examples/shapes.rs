@@ -0,0 +1,47 @@
+use hierro2d::{
+    component::{Component, Container, Ellipse, IntoComponent, Line, LineCap, Rect},
+    Application, Spring,
+};
+
+/// a circle that fades when clicked, an ellipse with an outline and a thick rounded line
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let dot = Ellipse::circle()
+            .position(0.1, 0.3)
+            .size(0.2, 0.4)
+            .color(1., 0.6, 0.2, 1.)
+            .on_click(|mut ctx, _| {
+                let id = ctx.current_id().unwrap();
+                ctx.spring(id, Spring::opacity(0.3));
+            });
+        let oval = Ellipse::new()
+            .position(0.4, 0.35)
+            .size(0.25, 0.3)
+            .color(0.2, 0.4, 0.6, 1.)
+            .stroke(3.)
+            .stroke_color(0.6, 0.8, 1., 1.);
+        let rule = Line::new()
+            .from(0.7, 0.3)
+            .to(0.9, 0.7)
+            .thickness(6.)
+            .cap(LineCap::Round)
+            .color(0.9, 0.9, 0.9, 1.);
+        Rect::new()
+            .size(1., 1.)
+            .with_child(dot)
+            .with_child(oval)
+            .with_child(rule)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("shapes");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,40 @@
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect, Text},
+    Application, Shortcuts,
+};
+
+/// F11 toggles fullscreen and Escape quits, wherever the focus is
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Rect::new()
+            .size(0.6, 0.1)
+            .center()
+            .color(0.2, 0.2, 0.25, 1.)
+            .with_child(
+                Text::new()
+                    .content("F11 for fullscreen, Escape to quit")
+                    .size(1., 1.),
+            )
+    }
+
+    fn shortcuts(&self, shortcuts: &mut Shortcuts) {
+        shortcuts
+            .register("F11".parse().unwrap(), |ctx| ctx.toggle_fullscreen())
+            .unwrap();
+        shortcuts
+            .register("Escape".parse().unwrap(), |mut ctx| ctx.exit())
+            .unwrap();
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("shortcuts");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
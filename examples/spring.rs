@@ -0,0 +1,34 @@
+use hierro2d::{
+    component::{Component, IntoComponent, Rect},
+    Application, Spring,
+};
+
+/// the square lights up under the pointer and bounces back when it leaves
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Rect::new()
+            .size(0.2, 0.2)
+            .center()
+            .color(0.4, 0.4, 0.4, 0.5)
+            .on_mouse_enter(|mut ctx, _| {
+                let id = ctx.current_id().unwrap();
+                ctx.spring(id, Spring::color(0.6, 0.6, 0.6, 0.8));
+            })
+            .on_mouse_leave(|mut ctx, _| {
+                let id = ctx.current_id().unwrap();
+                ctx.spring(id, Spring::color(0.4, 0.4, 0.4, 0.5).damping(12.));
+            })
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("spring");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
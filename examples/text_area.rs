@@ -0,0 +1,27 @@
+use hierro2d::{
+    component::{IntoComponent, TextArea},
+    Application,
+};
+
+/// a multi-line editor, with undo and redo on Ctrl+Z and Ctrl+Y and Tab inserting spaces
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        TextArea::new()
+            .content("first line\nsecond line\n\nselect with the mouse or Shift and the arrows")
+            .position(0.1, 0.1)
+            .size(0.8, 0.8)
+            .tab_size(2)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("text area");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,31 @@
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect, TextInput},
+    Application,
+};
+
+/// a single line field, composing with the input method of the system shows an underlined
+/// preedit until it is committed
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let input = TextInput::new()
+            .content("type here, or compose with an input method")
+            .size(1., 1.);
+        Rect::new()
+            .size(0.8, 0.1)
+            .center()
+            .color(0.2, 0.2, 0.25, 1.)
+            .with_child(input)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("text input");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,46 @@
+use hierro2d::{
+    component::{Component, Container, Img, IntoComponent, Rect, Transform},
+    Application,
+};
+
+const BADGE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/badge.png");
+
+/// a turned picture and a skewed, mirrored frame that lights up under the pointer along its
+/// transformed shape
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let badge = Img::new()
+            .content(BADGE)
+            .unwrap()
+            .position(0.15, 0.3)
+            .size(0.3, 0.4)
+            .transform(Transform::new().rotate(0.4).scale(1.2, 0.8));
+        let frame = Rect::new()
+            .position(0.55, 0.3)
+            .size(0.3, 0.4)
+            .radius(0.05)
+            .color(0.3, 0.6, 1., 1.)
+            .transform(Transform::new().skew(0.3, 0.).scale(-1., 1.))
+            .on_mouse_enter(|mut ctx, _| {
+                let id = ctx.current_id().unwrap();
+                ctx.set_color(id, 0.5, 0.8, 1., 1.);
+            })
+            .on_mouse_leave(|mut ctx, _| {
+                let id = ctx.current_id().unwrap();
+                ctx.set_color(id, 0.3, 0.6, 1., 1.);
+            });
+        Rect::new().size(1., 1.).with_child(badge).with_child(frame)
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("transform");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect},
+    Application, Easing, Property,
+};
+
+/// rows fade to blue under the pointer, the color change set in the handlers is animated by
+/// the transition
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        (0..5).fold(Rect::new().size(0.4, 0.6).center(), |list, index| {
+            let shade = 0.3 + index as f32 * 0.1;
+            list.with_child(
+                Rect::new()
+                    .position(0., index as f32 * 0.2)
+                    .size(1., 0.18)
+                    .color(shade, shade, shade, 1.)
+                    .transition(Property::Color, Duration::from_millis(200), Easing::EaseOut)
+                    .on_mouse_enter(|mut ctx, _| {
+                        let id = ctx.current_id().unwrap();
+                        ctx.set_color(id, 0.3, 0.6, 1., 1.);
+                    })
+                    .on_mouse_leave(move |mut ctx, _| {
+                        let id = ctx.current_id().unwrap();
+                        ctx.set_color(id, shade, shade, shade, 1.);
+                    }),
+            )
+        })
+    }
+
+    fn window(&self, window: &mut winit::window::Window) {
+        window.set_title("transition");
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = App {};
+    app.run().await;
+}
@@ -3,18 +3,17 @@ use hierro2d::{
     Application,
 };
 
+const BADGE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/badge.png");
+
 struct App;
 
 impl Application for App {
     fn view(self) -> impl IntoComponent {
-        let img = Img::new()
-            .content("/home/maruka/Pictures/bg.jpg")
-            .unwrap()
-            .size(0.1, 0.1);
+        let img = Img::new().content(BADGE).unwrap().size(0.1, 0.1);
         let text = Text::new()
             .content("Hello hierro2d!")
             .size(1., 1.)
-            .on_click(|ctx| ctx.toggle_fullscreen());
+            .on_click(|ctx, _| ctx.toggle_fullscreen());
         let sub_rect = Rect::new()
             .size(0.1, 0.1)
             .center()
@@ -0,0 +1,93 @@
+use std::f32::consts::PI;
+
+/// How the progress of an animation is spread over its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    Linear,
+    /// the CSS `ease` curve, slow start, fast middle and slow end
+    #[default]
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// pulls back a little before moving towards the target
+    BackIn,
+    /// overshoots the target a little before settling
+    BackOut,
+    /// overshoots the target and oscillates around it
+    ElasticOut,
+    /// bounces off the target like a dropped ball
+    BounceOut,
+    /// jumps between the given number of equal steps
+    Steps(u32),
+    /// a CSS `cubic-bezier(x1, y1, x2, y2)` curve, `x1` and `x2` are kept within 0 to 1
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// maps the linear progress `t` from 0 to 1 to the eased progress
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::Ease => cubic_bezier((0.25, 0.1), (0.25, 1.), t),
+            Easing::EaseIn => cubic_bezier((0.42, 0.), (1., 1.), t),
+            Easing::EaseOut => cubic_bezier((0., 0.), (0.58, 1.), t),
+            Easing::EaseInOut => cubic_bezier((0.42, 0.), (0.58, 1.), t),
+            Easing::BackIn => {
+                let c = 1.70158;
+                (c + 1.) * t.powi(3) - c * t.powi(2)
+            }
+            Easing::BackOut => {
+                let c = 1.70158;
+                1. + (c + 1.) * (t - 1.).powi(3) + c * (t - 1.).powi(2)
+            }
+            Easing::ElasticOut => match t {
+                t if t == 0. || t == 1. => t,
+                t => 2f32.powf(-10. * t) * ((t * 10. - 0.75) * (2. * PI / 3.)).sin() + 1.,
+            },
+            Easing::BounceOut => bounce_out(t),
+            Easing::Steps(steps) => {
+                let steps = steps.max(1) as f32;
+                (t * steps).floor() / steps
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier((x1.clamp(0., 1.), y1), (x2.clamp(0., 1.), y2), t)
+            }
+        }
+    }
+}
+
+/// the y of the curve through (0, 0), `p1`, `p2` and (1, 1) at `x`
+fn cubic_bezier(p1: (f32, f32), p2: (f32, f32), x: f32) -> f32 {
+    let sample = |a: f32, b: f32, t: f32| {
+        let u = 1. - t;
+        3. * u * u * t * a + 3. * u * t * t * b + t * t * t
+    };
+    // x grows monotonically with t as long as the control points stay within 0 to 1
+    let (mut low, mut high) = (0f32, 1f32);
+    for _ in 0..24 {
+        let t = (low + high) / 2.;
+        match sample(p1.0, p2.0, t) < x {
+            true => low = t,
+            false => high = t,
+        }
+    }
+    sample(p1.1, p2.1, (low + high) / 2.)
+}
+
+fn bounce_out(t: f32) -> f32 {
+    let (n, d) = (7.5625, 2.75);
+    if t < 1. / d {
+        n * t * t
+    } else if t < 2. / d {
+        let t = t - 1.5 / d;
+        n * t * t + 0.75
+    } else if t < 2.5 / d {
+        let t = t - 2.25 / d;
+        n * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d;
+        n * t * t + 0.984375
+    }
+}
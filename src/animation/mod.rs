@@ -0,0 +1,273 @@
+mod easing;
+mod spring;
+mod timeline;
+mod transition;
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+pub use self::{
+    easing::Easing,
+    spring::Spring,
+    timeline::{Property, Timeline, Tween},
+    transition::Transitions,
+};
+
+use crate::{component::Component, state::State};
+
+/// converts a position in fractions of the window, y pointing down, to screen space
+pub(crate) fn window_position(x: f32, y: f32) -> [f32; 4] {
+    [x * 2. - 1., -(y * 2. - 1.), 0., 0.]
+}
+
+/// converts a size in fractions of the window to screen space
+pub(crate) fn window_size(width: f32, height: f32) -> [f32; 4] {
+    [width * 2., height * 2., 0., 0.]
+}
+
+/// How often a timeline plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Repeat {
+    Times(u32),
+    Forever,
+}
+
+/// A timeline started on a component with [`crate::Context::animate`].
+#[derive(Debug, Clone)]
+pub struct Animation {
+    timeline: Timeline,
+    repeat: Repeat,
+    alternate: bool,
+}
+
+impl Animation {
+    pub fn new(timeline: impl Into<Timeline>) -> Self {
+        Self {
+            timeline: timeline.into(),
+            repeat: Repeat::Times(1),
+            alternate: false,
+        }
+    }
+
+    /// plays the timeline `count` times in total
+    pub fn repeat(mut self, count: u32) -> Self {
+        self.repeat = Repeat::Times(count.max(1));
+        self
+    }
+
+    /// plays the timeline until the animation is cancelled
+    pub fn forever(mut self) -> Self {
+        self.repeat = Repeat::Forever;
+        self
+    }
+
+    /// plays every other repetition backwards
+    pub fn alternate(mut self) -> Self {
+        self.alternate = true;
+        self
+    }
+}
+
+impl From<Tween> for Animation {
+    fn from(tween: Tween) -> Self {
+        Animation::new(tween)
+    }
+}
+
+impl From<Timeline> for Animation {
+    fn from(timeline: Timeline) -> Self {
+        Animation::new(timeline)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationId(u64);
+
+/// A tween of a running animation.
+struct Track {
+    /// when the tween starts within one repetition of the timeline
+    offset: Duration,
+    tween: Tween,
+    target: isize,
+    /// the value the property had when the tween first started
+    from: Option<[f32; 4]>,
+}
+
+struct Running {
+    id: AnimationId,
+    tracks: Vec<Track>,
+    duration: Duration,
+    repeat: Repeat,
+    alternate: bool,
+    start: Instant,
+}
+
+impl Running {
+    /// the time within the timeline to show at `now`, and whether the animation is over
+    fn time_at(&self, now: Instant) -> (Duration, bool) {
+        if self.duration.is_zero() {
+            return (self.duration, self.repeat != Repeat::Forever);
+        }
+        let elapsed = now - self.start;
+        let iteration = (elapsed.as_nanos() / self.duration.as_nanos()) as u32;
+        let (iteration, time, finished) = match self.repeat {
+            Repeat::Times(count) if iteration >= count => (count - 1, self.duration, true),
+            _ => (
+                iteration,
+                elapsed.saturating_sub(self.duration * iteration),
+                false,
+            ),
+        };
+        match self.alternate && iteration % 2 == 1 {
+            true => (self.duration - time, finished),
+            false => (time, finished),
+        }
+    }
+}
+
+/// The animations running in the window.
+#[derive(Default)]
+pub(crate) struct Animations {
+    running: Vec<Running>,
+    springs: Vec<spring::Running>,
+    /// the animation playing the latest change of each property with a transition
+    transitions: HashMap<(isize, Property), AnimationId>,
+    next_id: u64,
+}
+
+impl State {
+    pub(crate) fn animate(&mut self, target: isize, animation: Animation) -> AnimationId {
+        let id = AnimationId(self.animations.next_id);
+        self.animations.next_id += 1;
+        let mut tweens = vec![];
+        animation.timeline.flatten(Duration::ZERO, &mut tweens);
+        let tracks = tweens
+            .into_iter()
+            .map(|(offset, tween)| Track {
+                offset,
+                target: tween.target.unwrap_or(target),
+                tween,
+                from: None,
+            })
+            .collect();
+        self.animations.running.push(Running {
+            id,
+            tracks,
+            duration: animation.timeline.duration(),
+            repeat: animation.repeat,
+            alternate: animation.alternate,
+            start: self.now(),
+        });
+        self.request_redraw();
+        id
+    }
+
+    /// stops the animation where it is, returns false if it already finished
+    pub(crate) fn cancel_animation(&mut self, id: AnimationId) -> bool {
+        let len = self.animations.running.len();
+        self.animations.running.retain(|running| running.id != id);
+        self.animations.running.len() != len
+    }
+
+    pub(crate) fn is_animating(&self, id: AnimationId) -> bool {
+        self.animations
+            .running
+            .iter()
+            .any(|running| running.id == id)
+    }
+
+    /// whether any animation needs another frame
+    pub(crate) fn animating(&self) -> bool {
+        !self.animations.running.is_empty() || !self.animations.springs.is_empty()
+    }
+
+    /// moves every running animation to the current time and drops the finished ones
+    pub(crate) fn update_animations(&mut self) {
+        let now = self.now();
+        let mut running = std::mem::take(&mut self.animations.running);
+        running.retain_mut(|animation| {
+            let (time, finished) = animation.time_at(now);
+            let mut shown = HashSet::new();
+            for track in animation.tracks.iter_mut() {
+                let key = (track.target, track.tween.property);
+                // a tween played backwards past its start goes back to where it started, unless
+                // an earlier tween of the same property has the value by then
+                let started = time >= track.offset;
+                if !started && (track.from.is_none() || shown.contains(&key)) {
+                    continue;
+                }
+                let Some(comp) = self.components.get(track.target as usize) else {
+                    continue;
+                };
+                let tween = &track.tween;
+                let Some(from) = track.from.or_else(|| tween.property.get(comp)) else {
+                    continue;
+                };
+                track.from = Some(from);
+                let progress = match (started, tween.duration.is_zero()) {
+                    (false, _) => 0.,
+                    (true, true) => 1.,
+                    (true, false) => {
+                        (time - track.offset).as_secs_f32() / tween.duration.as_secs_f32()
+                    }
+                };
+                let t = tween.easing.apply(progress);
+                let value = std::array::from_fn(|i| from[i] + (tween.to[i] - from[i]) * t);
+                self.set_property(track.target, tween.property, value);
+                shown.insert(key);
+            }
+            !finished
+        });
+        self.animations.running = running;
+    }
+
+    /// moves the animations and springs of every component over to `successors[id]` in a new
+    /// view, dropping those of components that have none
+    pub(crate) fn retarget_animations(&mut self, successors: &[Option<isize>]) {
+        let successor = |id: isize| successors.get(id as usize).copied().flatten();
+        let animations = &mut self.animations;
+        animations.running.retain_mut(|running| {
+            running
+                .tracks
+                .retain_mut(|track| match successor(track.target) {
+                    Some(target) => {
+                        track.target = target;
+                        true
+                    }
+                    None => false,
+                });
+            !running.tracks.is_empty()
+        });
+        animations
+            .springs
+            .retain_mut(|running| match successor(running.target) {
+                Some(target) => {
+                    running.target = target;
+                    true
+                }
+                None => false,
+            });
+        animations.transitions = std::mem::take(&mut animations.transitions)
+            .into_iter()
+            .filter_map(|((id, property), animation)| Some(((successor(id)?, property), animation)))
+            .collect();
+    }
+
+    pub(crate) fn set_property(&mut self, id: isize, property: Property, value: [f32; 4]) {
+        let Some(comp) = self.components.get_mut(id as usize) else {
+            return;
+        };
+        match property {
+            Property::Position => {
+                let (x, y) = comp.get_position();
+                self.translate(id, (value[0] - x, value[1] - y));
+            }
+            Property::Size => comp.set_size((value[0], value[1])),
+            Property::Color => comp.set_color((value[0], value[1], value[2], value[3])),
+            Property::Radius => comp.set_corner_radii(value),
+            Property::Opacity => comp.set_opacity(value[0]),
+        }
+    }
+}
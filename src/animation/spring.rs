@@ -0,0 +1,181 @@
+use std::time::Instant;
+
+use crate::state::State;
+
+use super::{window_position, window_size, Property};
+
+/// longest step in seconds the simulation takes at once, short enough for stiff springs to stay
+/// stable when frames are slow
+const STEP: f32 = 0.001;
+/// distance to the target and speed under which a spring comes to rest
+const REST: f32 = 1e-4;
+/// lightest mass a spring is simulated with
+const MIN_MASS: f32 = 0.01;
+
+/// Pulls a property towards a target like a damped spring, without a fixed duration.
+///
+/// Starting a spring on a property that is already springing retargets it, keeping its current
+/// value and velocity so the motion stays smooth.
+#[derive(Debug, Clone, Copy)]
+pub struct Spring {
+    pub(crate) property: Property,
+    pub(crate) to: [f32; 4],
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+}
+
+impl Spring {
+    pub fn new(property: Property, to: [f32; 4]) -> Self {
+        Self {
+            property,
+            to,
+            stiffness: 170.,
+            damping: 26.,
+            mass: 1.,
+        }
+    }
+
+    /// moves the top left corner to `x`, `y` in fractions of the window from its top left corner
+    pub fn position(x: f32, y: f32) -> Self {
+        Self::new(Property::Position, window_position(x, y))
+    }
+
+    /// resizes to `width`, `height` in fractions of the window
+    pub fn size(width: f32, height: f32) -> Self {
+        Self::new(Property::Size, window_size(width, height))
+    }
+
+    pub fn color(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::new(Property::Color, [r, g, b, a])
+    }
+
+    pub fn radius(radius: f32) -> Self {
+        Self::new(Property::Radius, [radius; 4])
+    }
+
+    pub fn opacity(opacity: f32) -> Self {
+        Self::new(Property::Opacity, [opacity, 0., 0., 0.])
+    }
+
+    /// how hard the spring pulls towards the target, higher is faster
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// how quickly the motion loses energy, lower values overshoot and bounce
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// how heavy the animated value is, higher is slower, lighter than 0.01 counts as 0.01
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(MIN_MASS);
+        self
+    }
+}
+
+/// A spring moving a property of a component.
+pub(crate) struct Running {
+    pub(super) target: isize,
+    spring: Spring,
+    value: [f32; 4],
+    velocity: [f32; 4],
+    last: Instant,
+}
+
+impl Running {
+    /// advances the simulation by `dt` seconds
+    fn step(&mut self, dt: f32) {
+        let Spring {
+            to,
+            stiffness,
+            damping,
+            mass,
+            ..
+        } = self.spring;
+        // light or heavily damped springs need shorter steps to stay stable
+        let longest = STEP
+            .min(mass / damping.abs().max(f32::EPSILON))
+            .min((mass / stiffness.abs().max(f32::EPSILON)).sqrt());
+        let steps = (dt / longest).ceil().max(1.);
+        let h = dt / steps;
+        for _ in 0..steps as u32 {
+            let values = self.value.iter_mut().zip(self.velocity.iter_mut());
+            for ((value, velocity), to) in values.zip(to) {
+                let force = -stiffness * (*value - to) - damping * *velocity;
+                *velocity += force / mass * h;
+                *value += *velocity * h;
+            }
+        }
+    }
+
+    fn at_rest(&self) -> bool {
+        (0..4).all(|i| {
+            (self.value[i] - self.spring.to[i]).abs() < REST && self.velocity[i].abs() < REST
+        })
+    }
+}
+
+impl State {
+    /// starts `spring` on the component `target`, or retargets the one already moving the same
+    /// property
+    pub(crate) fn spring(&mut self, target: isize, spring: Spring) {
+        let now = self.now();
+        let springs = &mut self.animations.springs;
+        match springs
+            .iter_mut()
+            .find(|running| running.target == target && running.spring.property == spring.property)
+        {
+            Some(running) => running.spring = spring,
+            None => {
+                let Some(value) = self
+                    .components
+                    .get(target as usize)
+                    .and_then(|comp| spring.property.get(comp))
+                else {
+                    return;
+                };
+                springs.push(Running {
+                    target,
+                    spring,
+                    value,
+                    velocity: [0.; 4],
+                    last: now,
+                });
+            }
+        }
+        self.request_redraw();
+    }
+
+    /// stops the spring moving `property` of `target` where it is, returns false if there is
+    /// none
+    pub(crate) fn stop_spring(&mut self, target: isize, property: Property) -> bool {
+        let springs = &mut self.animations.springs;
+        let len = springs.len();
+        springs.retain(|running| running.target != target || running.spring.property != property);
+        springs.len() != len
+    }
+
+    /// moves every spring to the current time and drops the ones that came to rest
+    pub(crate) fn update_springs(&mut self) {
+        let now = self.now();
+        let mut springs = std::mem::take(&mut self.animations.springs);
+        springs.retain_mut(|running| {
+            // a stalled frame should not fling the value far past its target
+            let dt = (now - running.last).as_secs_f32().min(0.25);
+            running.last = now;
+            running.step(dt);
+            let rest = running.at_rest();
+            if rest {
+                running.value = running.spring.to;
+            }
+            self.set_property(running.target, running.spring.property, running.value);
+            !rest
+        });
+        springs.append(&mut self.animations.springs);
+        self.animations.springs = springs;
+    }
+}
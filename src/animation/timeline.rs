@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use crate::component::{Comp, Component};
+
+use super::{window_position, window_size, Easing};
+
+/// A property of a component that can be animated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Property {
+    /// the top left corner, moving the children along
+    Position,
+    Size,
+    Color,
+    /// the radii of the top left, top right, bottom right and bottom left corners
+    Radius,
+    Opacity,
+}
+
+impl Property {
+    pub(crate) const ALL: [Property; 5] = [
+        Property::Position,
+        Property::Size,
+        Property::Color,
+        Property::Radius,
+        Property::Opacity,
+    ];
+
+    /// the current value of the property on `comp`, `None` if the component does not have it
+    pub(crate) fn get(self, comp: &Comp) -> Option<[f32; 4]> {
+        match self {
+            Property::Position => {
+                let (x, y) = comp.get_position();
+                Some([x, y, 0., 0.])
+            }
+            Property::Size => {
+                let (width, height) = comp.get_size();
+                Some([width, height, 0., 0.])
+            }
+            Property::Color => comp.get_color().map(|(r, g, b, a)| [r, g, b, a]),
+            Property::Radius => Some(comp.corner_radii()),
+            Property::Opacity => comp.get_opacity().map(|opacity| [opacity, 0., 0., 0.]),
+        }
+    }
+}
+
+/// Animates one property from wherever it is when the tween starts to a target value.
+///
+/// Start from a specific value by putting a tween with a zero duration before it in a
+/// [`Timeline::sequence`].
+#[derive(Debug, Clone)]
+pub struct Tween {
+    pub(crate) property: Property,
+    pub(crate) to: [f32; 4],
+    pub(crate) duration: Duration,
+    pub(crate) easing: Easing,
+    pub(crate) target: Option<isize>,
+}
+
+impl Tween {
+    pub fn new(property: Property, to: [f32; 4]) -> Self {
+        Self {
+            property,
+            to,
+            duration: Duration::from_millis(300),
+            easing: Easing::default(),
+            target: None,
+        }
+    }
+
+    /// moves the top left corner to `x`, `y` in fractions of the window from its top left corner
+    pub fn position(x: f32, y: f32) -> Self {
+        Self::new(Property::Position, window_position(x, y))
+    }
+
+    /// resizes to `width`, `height` in fractions of the window
+    pub fn size(width: f32, height: f32) -> Self {
+        Self::new(Property::Size, window_size(width, height))
+    }
+
+    pub fn color(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::new(Property::Color, [r, g, b, a])
+    }
+
+    pub fn radius(radius: f32) -> Self {
+        Self::new(Property::Radius, [radius; 4])
+    }
+
+    pub fn opacity(opacity: f32) -> Self {
+        Self::new(Property::Opacity, [opacity, 0., 0., 0.])
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// animates the component `id` instead of the one the animation was started on
+    pub fn target(mut self, id: isize) -> Self {
+        self.target = Some(id);
+        self
+    }
+}
+
+/// Tweens arranged in time.
+#[derive(Debug, Clone)]
+pub enum Timeline {
+    Tween(Tween),
+    /// does nothing for a while, to space out the steps of a sequence
+    Delay(Duration),
+    /// plays the steps one after another
+    Sequence(Vec<Timeline>),
+    /// plays the steps at the same time, lasting as long as the longest
+    Parallel(Vec<Timeline>),
+}
+
+impl Timeline {
+    pub fn sequence<T: Into<Timeline>>(steps: impl IntoIterator<Item = T>) -> Self {
+        Timeline::Sequence(steps.into_iter().map(Into::into).collect())
+    }
+
+    pub fn parallel<T: Into<Timeline>>(steps: impl IntoIterator<Item = T>) -> Self {
+        Timeline::Parallel(steps.into_iter().map(Into::into).collect())
+    }
+
+    pub fn delay(duration: Duration) -> Self {
+        Timeline::Delay(duration)
+    }
+
+    pub fn duration(&self) -> Duration {
+        match self {
+            Timeline::Tween(tween) => tween.duration,
+            Timeline::Delay(duration) => *duration,
+            Timeline::Sequence(steps) => steps.iter().map(Timeline::duration).sum(),
+            Timeline::Parallel(steps) => steps
+                .iter()
+                .map(Timeline::duration)
+                .max()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// every tween of the timeline paired with the time it starts at, in playing order
+    pub(crate) fn flatten(&self, offset: Duration, tweens: &mut Vec<(Duration, Tween)>) {
+        match self {
+            Timeline::Tween(tween) => tweens.push((offset, tween.clone())),
+            Timeline::Delay(_) => {}
+            Timeline::Sequence(steps) => {
+                let mut offset = offset;
+                for step in steps {
+                    step.flatten(offset, tweens);
+                    offset += step.duration();
+                }
+            }
+            Timeline::Parallel(steps) => steps.iter().for_each(|step| step.flatten(offset, tweens)),
+        }
+    }
+}
+
+impl From<Tween> for Timeline {
+    fn from(tween: Tween) -> Self {
+        Timeline::Tween(tween)
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use crate::{
+    component::{Component, Components},
+    state::State,
+};
+
+use super::{Animation, Easing, Property, Tween};
+
+/// How changes to a property are animated.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Transition {
+    duration: Duration,
+    easing: Easing,
+}
+
+/// The transitions a component declared with [`crate::component::Component::transition`].
+#[derive(Debug, Clone, Default)]
+pub struct Transitions {
+    entries: Vec<(Property, Transition)>,
+}
+
+impl Transitions {
+    pub(crate) fn set(&mut self, property: Property, duration: Duration, easing: Easing) {
+        let transition = Transition { duration, easing };
+        match self
+            .entries
+            .iter_mut()
+            .find(|(bound, _)| *bound == property)
+        {
+            Some((_, bound)) => *bound = transition,
+            None => self.entries.push((property, transition)),
+        }
+    }
+
+    fn get(&self, property: Property) -> Option<Transition> {
+        self.entries
+            .iter()
+            .find(|(bound, _)| *bound == property)
+            .map(|(_, transition)| *transition)
+    }
+}
+
+impl State {
+    /// sets `property` of the component `id` to `value`, interpolating from the value shown
+    /// right now if the component declared a transition for the property
+    pub(crate) fn change(&mut self, id: isize, property: Property, value: [f32; 4]) {
+        let Some(comp) = self.components.get(id as usize) else {
+            return;
+        };
+        let transition = comp.transitions().get(property);
+        // the latest change wins over whatever was moving the property before
+        if let Some(previous) = self.animations.transitions.remove(&(id, property)) {
+            self.cancel_animation(previous);
+        }
+        self.stop_spring(id, property);
+        match transition {
+            Some(Transition { duration, easing }) => {
+                let tween = Tween::new(property, value)
+                    .duration(duration)
+                    .easing(easing);
+                let animation = self.animate(id, Animation::new(tween));
+                self.animations
+                    .transitions
+                    .insert((id, property), animation);
+            }
+            None => {
+                self.set_property(id, property, value);
+                self.request_redraw();
+            }
+        }
+    }
+    /// plays the declared transitions of the components of a new view from the values shown by
+    /// `old[origins[id]]`, the component each one replaced. Components moving along with their
+    /// parent only follow the transition of the parent.
+    pub(crate) fn transition_view(&mut self, old: &Components, origins: &[Option<isize>]) {
+        let mut moving = vec![false; self.components.len()];
+        for position in 0..self.tree_order.len() {
+            let id = self.tree_order[position];
+            if let Some(parent) = self.parents[id as usize] {
+                moving[id as usize] = moving[parent as usize];
+            }
+            let Some(origin) = origins[id as usize] else {
+                continue;
+            };
+            for property in Property::ALL {
+                if property == Property::Position && moving[id as usize] {
+                    continue;
+                }
+                let comp = &self.components[id as usize];
+                if comp.transitions().get(property).is_none() {
+                    continue;
+                }
+                let from = property.get(&old[origin as usize]);
+                let (Some(from), Some(to)) = (from, property.get(comp)) else {
+                    continue;
+                };
+                if from != to {
+                    self.set_property(id, property, from);
+                    self.change(id, property, to);
+                    moving[id as usize] |= property == Property::Position;
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,13 @@
 use std::future::Future;
 
-use crate::component::IntoComponent;
+use crate::{
+    clipboard::{Clipboard, SystemClipboard},
+    clock::{Clock, SystemClock},
+    component::IntoComponent,
+    context::Context,
+    event::PointerConfig,
+    shortcut::Shortcuts,
+};
 
 pub trait Application: Sized + 'static {
     fn view(self) -> impl IntoComponent;
@@ -10,4 +17,31 @@ pub trait Application: Sized + 'static {
     }
 
     fn window(&self, _window: &mut winit::window::Window) {}
+
+    /// registers application wide keyboard shortcuts, bind [`Context::exit`] to Escape here to
+    /// quit with it
+    fn shortcuts(&self, _shortcuts: &mut Shortcuts) {}
+
+    /// Called whenever the user asks to close the window, returns whether it may close.
+    ///
+    /// Return false to keep the window open, for example to ask for confirmation first, and
+    /// call [`Context::exit`] once the user agrees.
+    fn on_close_requested(&self) -> impl FnMut(Context) -> bool + 'static {
+        |_| true
+    }
+
+    /// thresholds for double clicks and long presses
+    fn pointer_config(&self) -> PointerConfig {
+        PointerConfig::default()
+    }
+
+    /// where copied text goes and pasted text comes from, the system clipboard by default
+    fn clipboard(&self) -> Box<dyn Clipboard> {
+        Box::new(SystemClipboard::default())
+    }
+
+    /// time source for animations, double clicks and long presses
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(SystemClock)
+    }
 }
@@ -1,8 +1,9 @@
+use std::time::Instant;
+
 use winit::{
     dpi::PhysicalSize,
     event::*,
-    event_loop::EventLoop,
-    keyboard::{Key, NamedKey},
+    event_loop::{ControlFlow, EventLoop},
     window::WindowBuilder,
 };
 
@@ -16,23 +17,18 @@ pub async fn run(app: impl Application) {
     let mut state = State::new(window, app).await;
 
     event_loop
-        .run(move |event, control_flow| match event {
-            Event::WindowEvent {
-                ref event,
-                window_id,
-            } if window_id == state.window().id() => {
-                if !state.input(event) {
+        .run(move |event, control_flow| {
+            match event {
+                Event::WindowEvent {
+                    ref event,
+                    window_id,
+                } if state
+                    .window()
+                    .is_some_and(|window| window.id() == window_id)
+                    && !state.input(event) =>
+                {
                     match event {
-                        WindowEvent::CloseRequested
-                        | WindowEvent::KeyboardInput {
-                            event:
-                                KeyEvent {
-                                    state: ElementState::Pressed,
-                                    logical_key: Key::Named(NamedKey::Escape),
-                                    ..
-                                },
-                            ..
-                        } => control_flow.exit(),
+                        WindowEvent::CloseRequested => state.close_requested(),
                         WindowEvent::Resized(physical_size) => {
                             state.resize(*physical_size);
                         }
@@ -58,8 +54,23 @@ pub async fn run(app: impl Application) {
                         _ => {}
                     }
                 }
+                Event::NewEvents(_) => state.update_timers(),
+                Event::AboutToWait => {
+                    state.flush_file_drop();
+                    if state.animating() {
+                        state.request_redraw();
+                    }
+                    // the event loop waits in real time, whatever clock the application uses
+                    control_flow.set_control_flow(match state.next_timeout() {
+                        Some(timeout) => ControlFlow::WaitUntil(Instant::now() + timeout),
+                        None => ControlFlow::Wait,
+                    });
+                }
+                _ => {}
+            }
+            if state.exit_requested {
+                control_flow.exit();
             }
-            _ => {}
         })
         .unwrap();
 }
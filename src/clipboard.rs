@@ -0,0 +1,66 @@
+/// Source and sink for copy and paste.
+///
+/// [`crate::Application::clipboard`] returns a [`SystemClipboard`] by default, return a
+/// [`MemoryClipboard`] instead to keep copied text inside the application, for example in tests.
+pub trait Clipboard {
+    fn get(&mut self) -> Option<String>;
+
+    fn set(&mut self, content: String);
+}
+
+/// A clipboard that lives only as long as the application, also handy in tests.
+#[derive(Default, Debug, Clone)]
+pub struct MemoryClipboard {
+    content: Option<String>,
+}
+
+impl Clipboard for MemoryClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.content.clone()
+    }
+
+    fn set(&mut self, content: String) {
+        self.content = Some(content);
+    }
+}
+
+/// The clipboard of the operating system, shared with other applications.
+///
+/// Falls back to a [`MemoryClipboard`] when the system clipboard cannot be reached, for example
+/// without a display server.
+pub struct SystemClipboard {
+    system: Option<arboard::Clipboard>,
+    fallback: MemoryClipboard,
+}
+
+impl Default for SystemClipboard {
+    fn default() -> Self {
+        let system = arboard::Clipboard::new()
+            .map_err(|err| log::warn!("system clipboard unavailable, copying in memory: {err}"))
+            .ok();
+        Self {
+            system,
+            fallback: MemoryClipboard::default(),
+        }
+    }
+}
+
+impl Clipboard for SystemClipboard {
+    fn get(&mut self) -> Option<String> {
+        match self.system.as_mut() {
+            Some(system) => system.get_text().ok(),
+            None => self.fallback.get(),
+        }
+    }
+
+    fn set(&mut self, content: String) {
+        match self.system.as_mut() {
+            Some(system) => {
+                if let Err(err) = system.set_text(content) {
+                    log::warn!("could not copy to the system clipboard: {err}");
+                }
+            }
+            None => self.fallback.set(content),
+        }
+    }
+}
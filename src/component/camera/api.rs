@@ -0,0 +1,53 @@
+use super::Camera;
+
+impl Camera {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width * 2., height * 2.);
+        self
+    }
+
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x * 2. - 1., -(y * 2. - 1.));
+        self
+    }
+
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// moves the world by `x`, `y` physical pixels on screen
+    pub fn pan(mut self, x: f32, y: f32) -> Self {
+        self.pan = (x, y);
+        self
+    }
+
+    /// scales the world around the center of the camera, clamped to the zoom range
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom.clamp(self.zoom_range.0, self.zoom_range.1);
+        self
+    }
+
+    /// rotates the world clockwise around the center of the camera, in radians
+    pub fn rotation(mut self, radians: f32) -> Self {
+        self.rotation = radians;
+        self
+    }
+
+    /// the smallest and largest zoom allowed, 0.1 to 10 by default
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.zoom_range = (min, max);
+        self.zoom = self.zoom.clamp(min, max);
+        self
+    }
+
+    /// zoom around the cursor with the mouse wheel, on by default
+    pub fn wheel_zoom(mut self, wheel_zoom: bool) -> Self {
+        self.wheel_zoom = wheel_zoom;
+        self
+    }
+}
@@ -0,0 +1,221 @@
+mod api;
+
+use super::{
+    container::Container, Affine, Component, Components, Handlers, IntoComponent, Transform,
+    Transitions,
+};
+
+/// A container that shows its children through a movable view, panned, zoomed and rotated
+/// around the center of its bounds, and zoomed around the cursor with the mouse wheel.
+///
+/// The children are laid out as usual and make up the world, world coordinates are physical
+/// pixels from the top left corner of the camera before the view is applied. Components outside
+/// the camera, such as toolbars drawn on top of it, stay in screen space. Like any transform,
+/// the view moves text along but keeps its size.
+pub struct Camera {
+    position: (f32, f32),
+    size: (f32, f32),
+    children: Components,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    depth: i32,
+    id: isize,
+    /// how far the world is moved on screen in physical pixels
+    pan: (f32, f32),
+    zoom: f32,
+    /// clockwise, in radians
+    rotation: f32,
+    zoom_range: (f32, f32),
+    wheel_zoom: bool,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            position: (-1., 1.),
+            size: (2., 2.),
+            children: vec![],
+            handlers: Handlers::default(),
+            transitions: Transitions::default(),
+            hidden: false,
+            focusable: false,
+            draggable: false,
+            transform: Transform::default(),
+            depth: 0,
+            id: 0,
+            pan: (0., 0.),
+            zoom: 1.,
+            rotation: 0.,
+            zoom_range: (0.1, 10.),
+            wheel_zoom: true,
+        }
+    }
+}
+
+impl Component for Camera {
+    fn children(&mut self) -> Option<&mut Components> {
+        Some(&mut self.children)
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.size = size;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        self.position
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.position = position;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn children_transform(&self, bounds: (f32, f32, f32, f32)) -> Affine {
+        self.view(bounds)
+    }
+
+    fn clips_children(&self) -> bool {
+        true
+    }
+}
+
+impl Camera {
+    pub(crate) fn pan_offset(&self) -> (f32, f32) {
+        self.pan
+    }
+
+    pub(crate) fn set_pan(&mut self, pan: (f32, f32)) {
+        self.pan = pan;
+    }
+
+    pub(crate) fn get_zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub(crate) fn get_rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    pub(crate) fn wheel_zooms(&self) -> bool {
+        self.wheel_zoom
+    }
+
+    /// the map from the laid out world to the screen, for a camera with `bounds` given as left,
+    /// top, width and height in physical pixels
+    pub(crate) fn view(&self, bounds: (f32, f32, f32, f32)) -> Affine {
+        let (left, top, width, height) = bounds;
+        let center = (left + width / 2., top + height / 2.);
+        let (sin, cos) = self.rotation.sin_cos();
+        Affine::translation(center.0 + self.pan.0, center.1 + self.pan.1)
+            * Affine([cos, -sin, 0., sin, cos, 0.])
+            * Affine([self.zoom, 0., 0., 0., self.zoom, 0.])
+            * Affine::translation(-center.0, -center.1)
+    }
+
+    /// changes zoom and rotation while the world under `anchor` stays where it is on screen,
+    /// `anchor` in physical pixels before the transforms of the camera and its ancestors
+    pub(crate) fn set_view_around(
+        &mut self,
+        zoom: f32,
+        rotation: f32,
+        anchor: (f32, f32),
+        bounds: (f32, f32, f32, f32),
+    ) {
+        let Some(inverse) = self.view(bounds).inverse() else {
+            return;
+        };
+        let world = inverse.apply(anchor);
+        self.zoom = zoom.clamp(self.zoom_range.0, self.zoom_range.1);
+        self.rotation = rotation;
+        let (left, top, width, height) = bounds;
+        let center = (left + width / 2., top + height / 2.);
+        let (x, y) = (
+            (world.0 - center.0) * self.zoom,
+            (world.1 - center.1) * self.zoom,
+        );
+        let (sin, cos) = self.rotation.sin_cos();
+        self.pan = (
+            anchor.0 - center.0 - (cos * x - sin * y),
+            anchor.1 - center.1 - (sin * x + cos * y),
+        );
+    }
+}
+
+impl Container for Camera {}
+
+impl IntoComponent for Camera {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::Camera(self)
+    }
+}
@@ -2,6 +2,8 @@ use wgpu::util::DeviceExt;
 
 use crate::vertex::Vertex;
 
+use super::Affine;
+
 pub fn create_vertex_buffer(device: &wgpu::Device, vertices: &[impl Vertex]) -> wgpu::Buffer {
     device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
         label: Some("Vertex Buffer"),
@@ -10,7 +12,10 @@ pub fn create_vertex_buffer(device: &wgpu::Device, vertices: &[impl Vertex]) ->
     })
 }
 
-pub fn create_index_buffer(device: &wgpu::Device, indicies: &[u16]) -> wgpu::Buffer {
+pub fn create_index_buffer<T: bytemuck::Pod>(
+    device: &wgpu::Device,
+    indicies: &[T],
+) -> wgpu::Buffer {
     device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
         label: Some("Index Buffer"),
         contents: bytemuck::cast_slice(indicies),
@@ -49,7 +54,7 @@ pub fn create_render_pipeline(
             entry_point: "fs_main",
             targets: &[Some(wgpu::ColorTargetState {
                 format: config.format,
-                blend: Some(wgpu::BlendState::REPLACE),
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                 write_mask: wgpu::ColorWrites::ALL,
             })],
         }),
@@ -57,12 +62,16 @@ pub fn create_render_pipeline(
             topology: wgpu::PrimitiveTopology::TriangleList,
             strip_index_format: None,
             front_face: wgpu::FrontFace::Ccw,
-            cull_mode: Some(wgpu::Face::Back),
+            // transforms may mirror the vertices and flip the winding of the triangles
+            cull_mode: None,
             polygon_mode: wgpu::PolygonMode::Fill,
             unclipped_depth: false,
             conservative: false,
         },
-        depth_stencil: None,
+        depth_stencil: Some(stencil_state(
+            wgpu::CompareFunction::Always,
+            wgpu::StencilOperation::Keep,
+        )),
         multisample: wgpu::MultisampleState {
             count: 1,
             mask: !0,
@@ -71,3 +80,139 @@ pub fn create_render_pipeline(
         multiview: None,
     })
 }
+
+/// Format of the buffer text is masked with to follow rounded ancestors. It has a depth aspect
+/// only because not every backend supports a stencil of its own.
+pub const STENCIL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24PlusStencil8;
+
+/// every pipeline drawn in the render pass needs a stencil state, most pass everywhere and
+/// leave the stencil untouched
+pub fn stencil_state(
+    compare: wgpu::CompareFunction,
+    pass_op: wgpu::StencilOperation,
+) -> wgpu::DepthStencilState {
+    let face = wgpu::StencilFaceState {
+        compare,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    };
+    wgpu::DepthStencilState {
+        format: STENCIL_FORMAT,
+        depth_write_enabled: false,
+        depth_compare: wgpu::CompareFunction::Always,
+        stencil: wgpu::StencilState {
+            front: face,
+            back: face,
+            read_mask: !0,
+            write_mask: !0,
+        },
+        bias: wgpu::DepthBiasState::default(),
+    }
+}
+
+pub fn create_stencil_view(
+    device: &wgpu::Device,
+    config: &wgpu::SurfaceConfiguration,
+) -> wgpu::TextureView {
+    device
+        .create_texture(&wgpu::TextureDescriptor {
+            label: Some("Stencil Texture"),
+            size: wgpu::Extent3d {
+                width: config.width,
+                height: config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: STENCIL_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        })
+        .create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+/// Signed distance from `p` to a box with half extents `b` and corner radius `r`, negative
+/// inside, the same function as `rectSDF` in the shaders.
+pub fn rect_sdf(p: (f32, f32), b: (f32, f32), r: f32) -> f32 {
+    let d = (p.0.abs() - b.0 + r, p.1.abs() - b.1 + r);
+    d.0.max(d.1).min(0.) + (d.0.max(0.).powi(2) + d.1.max(0.).powi(2)).sqrt() - r
+}
+
+/// The radius of the corner nearest to `p`, with y pointing down and `radii` given as top left,
+/// top right, bottom right and bottom left, the same function as `cornerRadius` in the shaders.
+pub fn corner_radius(p: (f32, f32), radii: [f32; 4]) -> f32 {
+    match (p.0 > 0., p.1 < 0.) {
+        (false, true) => radii[0],
+        (true, true) => radii[1],
+        (true, false) => radii[2],
+        (false, false) => radii[3],
+    }
+}
+
+/// Signed distance from the screen space `point` to the rounded rect, measured in the aspect
+/// corrected space the fragment shaders use so corners match what is drawn.
+pub fn rounded_rect_sdf(
+    position: (f32, f32),
+    size: (f32, f32),
+    radii: [f32; 4],
+    point: (f32, f32),
+    config: &wgpu::SurfaceConfiguration,
+) -> f32 {
+    let ratio = config.width as f32 / config.height as f32;
+    let center = (position.0 + size.0 / 2., position.1 - size.1 / 2.);
+    // y flipped to point down like the pixel coordinates of the shaders
+    let p = ((point.0 - center.0) * ratio, center.1 - point.1);
+    let half_size = (size.0 / 2. * ratio, size.1 / 2.);
+    rect_sdf(p, half_size, corner_radius(p, radii))
+}
+
+/// Whether the screen space `point` lies inside the rounded rect.
+pub fn rounded_rect_contains(
+    position: (f32, f32),
+    size: (f32, f32),
+    radii: [f32; 4],
+    point: (f32, f32),
+    config: &wgpu::SurfaceConfiguration,
+) -> bool {
+    rounded_rect_sdf(position, size, radii, point, config) <= 0.
+}
+
+/// Whether children are drawn past the edges of their parent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    #[default]
+    Visible,
+    /// cuts children off at the bounds of the parent, following its rounded corners
+    Clip,
+}
+
+/// Where the border of a [`crate::component::Rect`] is drawn relative to its edge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BorderAlign {
+    /// inside the bounds, the rect keeps its size
+    #[default]
+    Inner = 0,
+    /// half inside and half outside the bounds
+    Center = 1,
+    /// around the bounds, the rect grows by the border width on every side
+    Outer = 2,
+}
+
+/// how many rounded ancestors a component can be masked by in the shaders
+pub const MAX_CLIPS: usize = 4;
+
+/// A rounded or transformed ancestor that cuts off a component, in screen space. Axis aligned
+/// edges are already handled by the scissor rect, everything else is masked in the fragment
+/// shader.
+#[derive(Debug, Clone, Copy)]
+pub struct Clip {
+    pub position: (f32, f32),
+    pub size: (f32, f32),
+    /// top left, top right, bottom right and bottom left
+    pub radii: [f32; 4],
+    /// maps physical pixels on screen back to where they were before the ancestor was
+    /// transformed
+    pub inverse: Affine,
+}
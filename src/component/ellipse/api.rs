@@ -0,0 +1,59 @@
+use super::{Ellipse, Fill, ShapeKind};
+
+impl Ellipse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// the largest circle fitting in the bounds, centered, instead of an ellipse filling them
+    pub fn circle() -> Self {
+        Self {
+            kind: ShapeKind::Circle,
+            ..Self::default()
+        }
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.display_config.size = (width * 2., height * 2.);
+        self
+    }
+
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.display_config.position = (x * 2. - 1., -(y * 2. - 1.));
+        self
+    }
+
+    /// the fill, transparent for an outline only
+    pub fn color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.fill = Fill::Solid((r, g, b, a));
+        self
+    }
+
+    /// a solid color or a gradient over the bounds, replacing [`Ellipse::color`]
+    pub fn fill(mut self, fill: Fill) -> Self {
+        self.display_config.fill = fill;
+        self
+    }
+
+    /// draws the outline `width` physical pixels wide, centered on the edge
+    pub fn stroke(mut self, width: f32) -> Self {
+        self.display_config.stroke_width = width;
+        self
+    }
+
+    pub fn stroke_color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.stroke_color = (r, g, b, a);
+        self
+    }
+
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// from 0 for fully transparent to 1, applies to this component but not its children
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.display_config.opacity = opacity;
+        self
+    }
+}
@@ -0,0 +1,220 @@
+mod api;
+
+use super::{
+    container::Container,
+    shape::{self, ShapeKind, ShapeRenderer, ShapeUniform},
+    Affine, Clip, Component, Components, Fill, Handlers, IntoComponent, RectUniform, Transform,
+    Transitions,
+};
+
+struct DisplayConfig {
+    size: (f32, f32),
+    position: (f32, f32),
+    fill: Fill,
+    /// in physical pixels, centered on the outline
+    stroke_width: f32,
+    stroke_color: (f32, f32, f32, f32),
+    opacity: f32,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            size: (2., 2.),
+            position: (-1., 1.),
+            fill: Fill::solid(0., 0., 1., 1.),
+            stroke_width: 0.,
+            stroke_color: (0., 0., 0., 1.),
+            opacity: 1.,
+        }
+    }
+}
+
+/// An ellipse filling its bounds, or the largest circle fitting in them when built with
+/// [`Ellipse::circle`], with an optional stroke along the outline.
+#[derive(Default)]
+pub struct Ellipse {
+    /// either [`ShapeKind::Ellipse`] or [`ShapeKind::Circle`]
+    kind: ShapeKind,
+    display_config: DisplayConfig,
+    renderer: ShapeRenderer,
+    children: Components,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    world: Affine,
+    clips: Vec<Clip>,
+    depth: i32,
+    id: isize,
+}
+
+impl Component for Ellipse {
+    fn init(
+        &mut self,
+        device: &wgpu::Device,
+        _queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.renderer.init(device, config);
+    }
+
+    fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        let DisplayConfig {
+            size,
+            position,
+            ref fill,
+            stroke_width,
+            stroke_color,
+            opacity,
+        } = self.display_config;
+        let resolution = [config.width as f32, config.height as f32];
+        let rect = RectUniform::new(position, size, [0.; 4], opacity, resolution)
+            .with_clips(&self.clips)
+            .with_transform(self.world);
+        let shape = ShapeUniform::new(self.kind, stroke_width, stroke_color);
+        // room for the outer half of the stroke and the anti-aliased edge
+        let corners = shape::quad(position, size, stroke_width / 2. + 1., self.world, config);
+        self.renderer
+            .render(device, render_pass, rect, shape, fill, corners);
+    }
+
+    fn children(&mut self) -> Option<&mut Components> {
+        Some(&mut self.children)
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.display_config.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.display_config.size = size;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        self.display_config.position
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.display_config.position = position;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn contains(&self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        let (center, radii) = shape::ellipse_geometry(
+            self.display_config.position,
+            self.display_config.size,
+            config,
+        );
+        let (x, y) = shape::pixels(cursor, config);
+        let distance = match self.kind {
+            ShapeKind::Circle => (x - center.0).hypot(y - center.1) - radii.0.min(radii.1),
+            _ => shape::ellipse_sdf((x - center.0, y - center.1), radii),
+        };
+        distance <= self.display_config.stroke_width / 2.
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, _config: &wgpu::SurfaceConfiguration) {
+        self.world = transform;
+    }
+
+    fn get_color(&self) -> Option<(f32, f32, f32, f32)> {
+        self.display_config.fill.color()
+    }
+
+    fn set_color(&mut self, color: (f32, f32, f32, f32)) {
+        self.display_config.fill = Fill::Solid(color);
+    }
+
+    fn get_opacity(&self) -> Option<f32> {
+        Some(self.display_config.opacity)
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.display_config.opacity = opacity;
+    }
+}
+
+impl Container for Ellipse {}
+
+impl IntoComponent for Ellipse {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::Ellipse(self)
+    }
+}
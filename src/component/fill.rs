@@ -0,0 +1,356 @@
+use std::f32::consts::TAU;
+
+use wgpu::util::DeviceExt;
+
+/// how many color stops the shaders read, later stops are left out
+pub(crate) const MAX_STOPS: usize = 8;
+
+/// A color at `offset` along a gradient, from 0 at its start to 1 at its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    pub offset: f32,
+    pub color: (f32, f32, f32, f32),
+}
+
+/// What the inside of a component is painted with.
+///
+/// Gradients are laid out over the bounds of the component with y pointing down, before the
+/// component is transformed. Stops are added with [`Fill::stop`], before the first and after the
+/// last stop the gradient keeps their colors. Stops past the eighth are left out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    Solid((f32, f32, f32, f32)),
+    /// colors along the direction `angle` radians from pointing right, turning clockwise,
+    /// reaching from one corner of the bounds to the opposite one
+    Linear {
+        angle: f32,
+        stops: Vec<ColorStop>,
+    },
+    /// rings around `center`, a fraction of the bounds, reaching the farthest corner
+    Radial {
+        center: (f32, f32),
+        stops: Vec<ColorStop>,
+    },
+    /// colors sweeping clockwise once around `center`, starting `angle` radians from pointing
+    /// right
+    Conic {
+        center: (f32, f32),
+        angle: f32,
+        stops: Vec<ColorStop>,
+    },
+}
+
+impl Fill {
+    pub fn solid(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::Solid((r, g, b, a))
+    }
+
+    pub fn linear(angle: f32) -> Self {
+        Self::Linear {
+            angle,
+            stops: vec![],
+        }
+    }
+
+    /// `x` and `y` from 0 to 1 across the bounds
+    pub fn radial(x: f32, y: f32) -> Self {
+        Self::Radial {
+            center: (x, y),
+            stops: vec![],
+        }
+    }
+
+    /// `x` and `y` from 0 to 1 across the bounds
+    pub fn conic(x: f32, y: f32, angle: f32) -> Self {
+        Self::Conic {
+            center: (x, y),
+            angle,
+            stops: vec![],
+        }
+    }
+
+    /// adds a color at `offset` from 0 to 1 along the gradient, stops at the same offset make a
+    /// hard edge in the order they were added, ignored by solid fills
+    pub fn stop(mut self, offset: f32, r: f32, g: f32, b: f32, a: f32) -> Self {
+        if let Self::Linear { stops, .. } | Self::Radial { stops, .. } | Self::Conic { stops, .. } =
+            &mut self
+        {
+            let index = stops.partition_point(|stop| stop.offset <= offset);
+            stops.insert(
+                index,
+                ColorStop {
+                    offset,
+                    color: (r, g, b, a),
+                },
+            );
+        }
+        self
+    }
+
+    /// the color of a solid fill, `None` for gradients
+    pub fn color(&self) -> Option<(f32, f32, f32, f32)> {
+        match self {
+            Self::Solid(color) => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// whether any part of the fill is not fully transparent
+    pub(crate) fn visible(&self) -> bool {
+        match self {
+            Self::Solid(color) => color.3 > 0.,
+            Self::Linear { stops, .. } | Self::Radial { stops, .. } | Self::Conic { stops, .. } => {
+                stops.iter().take(MAX_STOPS).any(|stop| stop.color.3 > 0.)
+            }
+        }
+    }
+
+    /// the color at `p` from the center of bounds with the half size `half`, both with y pointing
+    /// down, the same function as `fillColor` in the shaders
+    pub(crate) fn color_at(&self, p: (f32, f32), half: (f32, f32)) -> (f32, f32, f32, f32) {
+        let center =
+            |center: (f32, f32)| ((center.0 * 2. - 1.) * half.0, (center.1 * 2. - 1.) * half.1);
+        let (t, stops) = match self {
+            Self::Solid(color) => return *color,
+            Self::Linear { angle, stops } => {
+                let dir = (angle.cos(), angle.sin());
+                let extent = dir.0.abs() * half.0 + dir.1.abs() * half.1;
+                let t = match extent > 0. {
+                    true => ((p.0 * dir.0 + p.1 * dir.1) / extent + 1.) / 2.,
+                    false => 0.,
+                };
+                (t, stops)
+            }
+            Self::Radial { center: c, stops } => {
+                let c = center(*c);
+                let reach = (half.0 + c.0.abs()).hypot(half.1 + c.1.abs());
+                let t = match reach > 0. {
+                    true => (p.0 - c.0).hypot(p.1 - c.1) / reach,
+                    false => 0.,
+                };
+                (t, stops)
+            }
+            Self::Conic {
+                center: c,
+                angle,
+                stops,
+            } => {
+                let c = center(*c);
+                let t = (((p.1 - c.1).atan2(p.0 - c.0) - angle) / TAU).rem_euclid(1.);
+                (t, stops)
+            }
+        };
+        let stops = &stops[..stops.len().min(MAX_STOPS)];
+        let Some(first) = stops.first() else {
+            return (0., 0., 0., 0.);
+        };
+        let mut color = first.color;
+        for pair in stops.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            if t >= end.offset {
+                color = end.color;
+            } else if t > start.offset {
+                let k = (t - start.offset) / (end.offset - start.offset);
+                let mix = |a: f32, b: f32| a + (b - a) * k;
+                let (a, b) = (start.color, end.color);
+                color = (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2), mix(a.3, b.3));
+                break;
+            } else {
+                break;
+            }
+        }
+        color
+    }
+
+    pub(crate) fn uniform(&self) -> FillUniform {
+        let mut uniform = FillUniform {
+            center: [0.5, 0.5],
+            angle: 0.,
+            kind: 0,
+            stop_count: 0,
+            _padding: [0; 3],
+            offsets: [[0.; 4]; MAX_STOPS / 4],
+            colors: [[0.; 4]; MAX_STOPS],
+        };
+        let solid;
+        let stops = match self {
+            Self::Solid(color) => {
+                solid = [ColorStop {
+                    offset: 0.,
+                    color: *color,
+                }];
+                &solid[..]
+            }
+            Self::Linear { angle, stops } => {
+                uniform.kind = 1;
+                uniform.angle = *angle;
+                stops
+            }
+            Self::Radial { center, stops } => {
+                uniform.kind = 2;
+                uniform.center = [center.0, center.1];
+                stops
+            }
+            Self::Conic {
+                center,
+                angle,
+                stops,
+            } => {
+                uniform.kind = 3;
+                uniform.center = [center.0, center.1];
+                uniform.angle = *angle;
+                stops
+            }
+        };
+        uniform.stop_count = stops.len().min(MAX_STOPS) as u32;
+        for (i, stop) in stops.iter().take(MAX_STOPS).enumerate() {
+            let (r, g, b, a) = stop.color;
+            uniform.offsets[i / 4][i % 4] = stop.offset;
+            uniform.colors[i] = [r, g, b, a];
+        }
+        uniform
+    }
+}
+
+#[repr(C)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+pub(crate) struct FillUniform {
+    /// a fraction of the bounds
+    pub center: [f32; 2],
+    pub angle: f32,
+    /// solid, linear, radial or conic
+    pub kind: u32,
+    pub stop_count: u32,
+    pub _padding: [u32; 3],
+    /// four offsets in each element
+    pub offsets: [[f32; 4]; MAX_STOPS / 4],
+    pub colors: [[f32; 4]; MAX_STOPS],
+}
+
+pub(crate) fn create_fill_buffer(device: &wgpu::Device, fill: &Fill) -> wgpu::Buffer {
+    device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("fill buffer"),
+        contents: bytemuck::cast_slice(&[fill.uniform()]),
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    const BLACK: (f32, f32, f32, f32) = (0., 0., 0., 1.);
+    const RED: (f32, f32, f32, f32) = (1., 0., 0., 1.);
+    const WHITE: (f32, f32, f32, f32) = (1., 1., 1., 1.);
+
+    fn stops(fill: Fill) -> Fill {
+        fill.stop(0., 0., 0., 0., 1.)
+            .stop(0.5, 1., 0., 0., 1.)
+            .stop(1., 1., 1., 1., 1.)
+    }
+
+    #[track_caller]
+    fn assert_color(fill: &Fill, p: (f32, f32), half: (f32, f32), expected: (f32, f32, f32, f32)) {
+        let color = fill.color_at(p, half);
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+        assert!(
+            close(color.0, expected.0)
+                && close(color.1, expected.1)
+                && close(color.2, expected.2)
+                && close(color.3, expected.3),
+            "{color:?} at {p:?}, expected {expected:?}"
+        );
+    }
+
+    #[test]
+    fn linear_reaches_corner_to_corner() {
+        let fill = stops(Fill::linear(0.));
+        let half = (2., 1.);
+        assert_color(&fill, (-2., 0.), half, BLACK);
+        assert_color(&fill, (0., 0.), half, RED);
+        assert_color(&fill, (1., 0.), half, (1., 0.5, 0.5, 1.));
+        assert_color(&fill, (2., 0.), half, WHITE);
+
+        // pointing down, along the diagonal of a square
+        let fill = stops(Fill::linear(FRAC_PI_2));
+        assert_color(&fill, (0., -1.), (1., 1.), BLACK);
+        assert_color(&fill, (0., 1.), (1., 1.), WHITE);
+    }
+
+    #[test]
+    fn colors_hold_past_the_end_stops_and_switch_at_shared_offsets() {
+        let fill = Fill::linear(0.)
+            .stop(0.25, 0., 0., 0., 1.)
+            .stop(0.5, 0., 0., 0., 1.)
+            .stop(0.5, 1., 1., 1., 1.)
+            .stop(0.75, 1., 1., 1., 1.);
+        let half = (1., 1.);
+        assert_color(&fill, (-1., 0.), half, BLACK);
+        assert_color(&fill, (-0.01, 0.), half, BLACK);
+        assert_color(&fill, (0.01, 0.), half, WHITE);
+        assert_color(&fill, (1., 0.), half, WHITE);
+    }
+
+    #[test]
+    fn radial_reaches_the_farthest_corner() {
+        let fill = stops(Fill::radial(0.5, 0.5));
+        let half = (3., 4.);
+        assert_color(&fill, (0., 0.), half, BLACK);
+        assert_color(&fill, (0., 2.5), half, RED);
+        assert_color(&fill, (-3., -4.), half, WHITE);
+
+        // from the top left corner the far corner is twice as far away
+        let fill = stops(Fill::radial(0., 0.));
+        assert_color(&fill, (-3., -4.), half, BLACK);
+        assert_color(&fill, (0., 0.), half, RED);
+        assert_color(&fill, (3., 4.), half, WHITE);
+    }
+
+    #[test]
+    fn conic_sweeps_clockwise_from_its_angle() {
+        let fill = Fill::conic(0.5, 0.5, 0.)
+            .stop(0., 0., 0., 0., 1.)
+            .stop(1., 1., 1., 1., 1.);
+        let half = (1., 1.);
+        let grey = |t: f32| (t, t, t, 1.);
+        assert_color(&fill, (1., 0.), half, BLACK);
+        // y points down, a quarter turn clockwise is below the center
+        assert_color(&fill, (0., 1.), half, grey(0.25));
+        assert_color(&fill, (-1., 0.), half, grey(0.5));
+        assert_color(&fill, (0., -1.), half, grey(0.75));
+
+        let turned = Fill::conic(0.5, 0.5, FRAC_PI_2)
+            .stop(0., 0., 0., 0., 1.)
+            .stop(1., 1., 1., 1., 1.);
+        assert_color(&turned, (0., 1.), half, BLACK);
+        assert_color(&turned, (1., 0.), half, grey(0.75));
+    }
+
+    #[test]
+    fn conic_wraps_around_at_its_angle() {
+        let fill = Fill::conic(0.5, 0.5, 0.)
+            .stop(0., 0., 0., 0., 1.)
+            .stop(1., 1., 1., 1., 1.);
+        let half = (1., 1.);
+        // just below the start the sweep begins, just above it the sweep ends
+        assert_color(&fill, (1., 1e-4), half, BLACK);
+        assert_color(&fill, (1., -1e-4), half, WHITE);
+        // the same holds for any starting angle, past a full turn
+        let turned = Fill::conic(0.5, 0.5, FRAC_PI_2 + TAU)
+            .stop(0., 0., 0., 0., 1.)
+            .stop(1., 1., 1., 1., 1.);
+        assert_color(&turned, (1e-4, 1.), half, WHITE);
+        assert_color(&turned, (-1e-4, 1.), half, BLACK);
+    }
+
+    #[test]
+    fn gradients_without_stops_are_transparent() {
+        assert_eq!(
+            Fill::linear(0.).color_at((0., 0.), (1., 1.)),
+            (0., 0., 0., 0.)
+        );
+        assert!(!Fill::conic(0.5, 0.5, 0.).visible());
+    }
+}
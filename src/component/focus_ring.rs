@@ -0,0 +1,75 @@
+use super::{Affine, Component, Rect};
+
+const WIDTH: f32 = 2.;
+const GAP: f32 = 2.;
+
+/// Outline drawn around the focused component when focus was moved with the keyboard.
+pub(crate) struct FocusRing {
+    edges: [Rect; 4],
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            edges: std::array::from_fn(|_| Rect::new().color(0.3, 0.6, 1., 1.)),
+        }
+    }
+}
+
+impl FocusRing {
+    pub fn init(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.edges
+            .iter_mut()
+            .for_each(|edge| edge.init(device, queue, config));
+    }
+
+    /// places the ring just outside the screen space rectangle at `position` with `size`, then
+    /// transformed like the component it surrounds
+    pub fn layout(
+        &mut self,
+        position: (f32, f32),
+        size: (f32, f32),
+        transform: Affine,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let (dx, dy) = (2. / config.width as f32, 2. / config.height as f32);
+        let (gap_x, gap_y) = (GAP * dx, GAP * dy);
+        let (width_x, width_y) = (WIDTH * dx, WIDTH * dy);
+        let left = position.0 - gap_x - width_x;
+        let top = position.1 + gap_y + width_y;
+        let outer = (
+            size.0 + 2. * (gap_x + width_x),
+            size.1 + 2. * (gap_y + width_y),
+        );
+        let [top_edge, bottom_edge, left_edge, right_edge] = &mut self.edges;
+        top_edge.set_position((left, top));
+        top_edge.set_size((outer.0, width_y));
+        bottom_edge.set_position((left, top - outer.1 + width_y));
+        bottom_edge.set_size((outer.0, width_y));
+        left_edge.set_position((left, top));
+        left_edge.set_size((width_x, outer.1));
+        right_edge.set_position((left + outer.0 - width_x, top));
+        right_edge.set_size((width_x, outer.1));
+        self.edges
+            .iter_mut()
+            .for_each(|edge| edge.set_world_transform(transform, config));
+    }
+
+    pub fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        self.edges
+            .iter_mut()
+            .for_each(|edge| edge.render(device, config, render_pass));
+    }
+}
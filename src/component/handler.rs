@@ -0,0 +1,40 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    context::Context,
+    event::{DragEvent, FileDropEvent, GestureEvent, KeyboardEvent, PointerEvent, ScrollEvent},
+};
+
+pub(crate) type Handler<E> = Rc<RefCell<dyn FnMut(Context, &mut E)>>;
+
+pub(crate) fn handler<E>(f: impl FnMut(Context, &mut E) + 'static) -> Option<Handler<E>> {
+    Some(Rc::new(RefCell::new(f)))
+}
+
+/// Event handlers registered on a component, see the `on_*` methods of [`super::Component`].
+#[derive(Default)]
+pub struct Handlers {
+    pub(crate) click: Option<Handler<PointerEvent>>,
+    pub(crate) right_click: Option<Handler<PointerEvent>>,
+    pub(crate) middle_click: Option<Handler<PointerEvent>>,
+    pub(crate) double_click: Option<Handler<PointerEvent>>,
+    pub(crate) long_press: Option<Handler<PointerEvent>>,
+    pub(crate) mouse_down: Option<Handler<PointerEvent>>,
+    pub(crate) mouse_up: Option<Handler<PointerEvent>>,
+    pub(crate) mouse_enter: Option<Handler<PointerEvent>>,
+    pub(crate) mouse_leave: Option<Handler<PointerEvent>>,
+    pub(crate) mouse_move: Option<Handler<PointerEvent>>,
+    pub(crate) scroll: Option<Handler<ScrollEvent>>,
+    pub(crate) pointer_capture: Option<Handler<PointerEvent>>,
+    pub(crate) scroll_capture: Option<Handler<ScrollEvent>>,
+    pub(crate) key_down: Option<Handler<KeyboardEvent>>,
+    pub(crate) key_up: Option<Handler<KeyboardEvent>>,
+    pub(crate) key_capture: Option<Handler<KeyboardEvent>>,
+    pub(crate) drag_start: Option<Handler<DragEvent>>,
+    pub(crate) drag: Option<Handler<DragEvent>>,
+    pub(crate) drop: Option<Handler<DragEvent>>,
+    pub(crate) file_drop: Option<Handler<FileDropEvent>>,
+    pub(crate) pinch: Option<Handler<GestureEvent>>,
+    pub(crate) pan: Option<Handler<GestureEvent>>,
+    pub(crate) rotate: Option<Handler<GestureEvent>>,
+}
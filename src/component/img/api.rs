@@ -2,16 +2,17 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
-use super::Img;
+use super::{Img, Overflow};
 
 impl Img {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// shows the image file at `path`, fails if it cannot be read or decoded
     pub fn content(mut self, path: impl Into<PathBuf>) -> Result<Self> {
         let path: PathBuf = path.into();
-        self.texture_raw = std::fs::read(path)?;
+        self.set_content(&std::fs::read(path)?)?;
         Ok(self)
     }
 
@@ -30,8 +31,38 @@ impl Img {
         self
     }
 
+    /// rounds all four corners
     pub fn radius(mut self, radius: f32) -> Self {
-        self.display_config.radius = radius;
+        self.display_config.radii = [radius; 4];
+        self
+    }
+
+    pub fn corner_radii(
+        mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        self.display_config.radii = [top_left, top_right, bottom_right, bottom_left];
+        self
+    }
+
+    /// let clicks on fully transparent pixels fall through to whatever is underneath
+    pub fn ignore_transparent(mut self, ignore_transparent: bool) -> Self {
+        self.set_ignore_transparent(ignore_transparent);
+        self
+    }
+
+    /// from 0 for fully transparent to 1, applies to this component but not its children
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.display_config.opacity = opacity;
+        self
+    }
+
+    /// with [`Overflow::Clip`] children are cut off at the bounds and rounded corners
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
         self
     }
 }
@@ -11,7 +11,10 @@ use self::{
     vertex::ImgVertex,
 };
 
-use super::{common, Component, Components, IntoComponent};
+use super::{
+    common, Affine, Clip, Component, Components, Handlers, IntoComponent, Overflow, Transform,
+    Transitions,
+};
 
 #[derive(Default)]
 pub struct Img {
@@ -25,16 +28,54 @@ pub struct Img {
     rect_bind_group_layout: Option<wgpu::BindGroupLayout>,
     rect_bind_group: Option<wgpu::BindGroup>,
     children: Components,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    world: Affine,
+    overflow: Overflow,
+    clips: Vec<Clip>,
     depth: i32,
-    texture_raw: Vec<u8>,
+    /// decoded content waiting to be uploaded on the next frame
+    pending: Option<image::RgbaImage>,
     texture: Option<Texture>,
+    ignore_transparent: bool,
+    alpha_mask: Option<AlphaMask>,
     id: isize,
 }
 
+/// alpha channel kept on the CPU for hit testing
+struct AlphaMask {
+    width: u32,
+    height: u32,
+    alpha: Vec<u8>,
+}
+
+impl AlphaMask {
+    fn new(image: &image::RgbaImage) -> Self {
+        Self {
+            width: image.width(),
+            height: image.height(),
+            alpha: image.pixels().map(|pixel| pixel.0[3]).collect(),
+        }
+    }
+
+    /// whether the pixel at `uv` is not fully transparent
+    fn opaque_at(&self, uv: (f32, f32)) -> bool {
+        let x = ((uv.0 * self.width as f32) as u32).min(self.width - 1);
+        let y = ((uv.1 * self.height as f32) as u32).min(self.height - 1);
+        self.alpha[(y * self.width + x) as usize] > 0
+    }
+}
+
 struct DisplayConfig {
     size: (f32, f32),
     position: (f32, f32),
-    radius: f32,
+    /// top left, top right, bottom right and bottom left
+    radii: [f32; 4],
+    opacity: f32,
 }
 
 impl Default for DisplayConfig {
@@ -42,7 +83,8 @@ impl Default for DisplayConfig {
         Self {
             size: (2., 2.),
             position: (-1., 1.),
-            radius: 0.,
+            radii: [0.; 4],
+            opacity: 1.,
         }
     }
 }
@@ -54,8 +96,8 @@ impl Component for Img {
         queue: &wgpu::Queue,
         config: &wgpu::SurfaceConfiguration,
     ) {
-        let texture = Texture::from_bytes(device, queue, &self.texture_raw).unwrap();
-        let indices = [0, 1, 2, 2, 3, 0];
+        self.load_texture(device, queue);
+        let indices: [u16; 6] = [0, 1, 2, 2, 3, 0];
         let index_buffer = common::create_index_buffer(device, &indices);
         let texture_bind_group_layout = create_texture_bind_group_layout(device);
         let rect_bind_group_layout = create_rect_bind_group_layout(device);
@@ -71,7 +113,17 @@ impl Component for Img {
         self.indices_length = Some(indices.len() as _);
         self.texture_bind_group_layout = Some(texture_bind_group_layout);
         self.rect_bind_group_layout = Some(rect_bind_group_layout);
-        self.texture = Some(texture);
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        _config: &wgpu::SurfaceConfiguration,
+    ) {
+        if self.pending.is_some() {
+            self.load_texture(device, queue);
+        }
     }
 
     fn render<'a, 'b>(
@@ -82,10 +134,14 @@ impl Component for Img {
     ) where
         'a: 'b,
     {
+        // an image without content is an invisible placeholder
+        let Some(texture) = self.texture.as_ref() else {
+            return;
+        };
         let texture_bind_group = self.create_texture_bind_group(
             device,
             self.texture_bind_group_layout.as_ref().unwrap(),
-            self.texture.as_ref().unwrap(),
+            texture,
         );
         self.texture_bind_group = Some(texture_bind_group);
 
@@ -96,7 +152,7 @@ impl Component for Img {
         );
         self.rect_bind_group = Some(rect_bind_group);
 
-        let vertices = self.create_vertices();
+        let vertices = self.create_vertices(config);
         let vertex_buffer = common::create_vertex_buffer(device, &vertices);
         self.vertex_buffer = Some(vertex_buffer);
 
@@ -146,6 +202,126 @@ impl Component for Img {
     fn set_id(&mut self, id: isize) {
         self.id = id;
     }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn contains(&self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        let (x, y) = self.display_config.position;
+        let (width, height) = self.display_config.size;
+        if !common::rounded_rect_contains(
+            (x, y),
+            (width, height),
+            self.display_config.radii,
+            cursor,
+            config,
+        ) {
+            return false;
+        }
+        match &self.alpha_mask {
+            Some(mask) => mask.opaque_at(((cursor.0 - x) / width, (y - cursor.1) / height)),
+            None => true,
+        }
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn clips_children(&self) -> bool {
+        self.overflow == Overflow::Clip
+    }
+
+    fn corner_radii(&self) -> [f32; 4] {
+        self.display_config.radii
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, _config: &wgpu::SurfaceConfiguration) {
+        self.world = transform;
+    }
+
+    fn set_corner_radii(&mut self, radii: [f32; 4]) {
+        self.display_config.radii = radii;
+    }
+
+    fn get_opacity(&self) -> Option<f32> {
+        Some(self.display_config.opacity)
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.display_config.opacity = opacity;
+    }
+}
+
+impl Img {
+    /// replaces the image shown by the encoded `bytes`, uploaded on the next frame
+    pub(crate) fn set_content(&mut self, bytes: &[u8]) -> anyhow::Result<()> {
+        let image = image::load_from_memory(bytes)?.to_rgba8();
+        self.alpha_mask = self.ignore_transparent.then(|| AlphaMask::new(&image));
+        self.pending = Some(image);
+        Ok(())
+    }
+
+    fn set_ignore_transparent(&mut self, ignore_transparent: bool) {
+        self.ignore_transparent = ignore_transparent;
+        self.alpha_mask = match (&self.pending, ignore_transparent) {
+            (Some(image), true) => Some(AlphaMask::new(image)),
+            (None, true) => self.alpha_mask.take(),
+            (_, false) => None,
+        };
+    }
+
+    fn load_texture(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let Some(image) = self.pending.take() else {
+            return;
+        };
+        self.texture = Some(Texture::from_rgba(device, queue, &image, Some("texture")));
+    }
 }
 
 impl IntoComponent for Img {
@@ -8,30 +8,36 @@ use super::{
 };
 
 impl Img {
-    pub(crate) fn create_vertices(&self) -> Vec<ImgVertex> {
+    pub(crate) fn create_vertices(&self, config: &wgpu::SurfaceConfiguration) -> Vec<ImgVertex> {
         let (x, y) = self.display_config.position;
         let (width, height) = self.display_config.size;
         let vertices = &[
             ImgVertex {
-                position: [x, y - height],
+                position: self.corner((x, y - height), config),
                 tex_coords: [0., 1.],
             }, // A
             ImgVertex {
-                position: [x + width, y - height],
+                position: self.corner((x + width, y - height), config),
                 tex_coords: [1., 1.],
             }, // B
             ImgVertex {
-                position: [x + width, y],
+                position: self.corner((x + width, y), config),
                 tex_coords: [1., 0.],
             }, // C
             ImgVertex {
-                position: [x, y],
+                position: self.corner((x, y), config),
                 tex_coords: [0., 0.],
             }, // D
         ];
         vertices.into()
     }
 
+    /// where the corner at `point` is drawn once transformed
+    fn corner(&self, point: (f32, f32), config: &wgpu::SurfaceConfiguration) -> [f32; 2] {
+        let (x, y) = self.world.apply_ndc(point, config);
+        [x, y]
+    }
+
     pub(crate) fn create_texture_bind_group(
         &self,
         device: &wgpu::Device,
@@ -47,19 +53,18 @@ impl Img {
         layout: &wgpu::BindGroupLayout,
         config: &wgpu::SurfaceConfiguration,
     ) -> wgpu::BindGroup {
-        let (x, y) = self.display_config.position;
-        let (width, height) = self.display_config.size;
-        let radius = self.display_config.radius;
         let resolution = [config.width as f32, config.height as f32];
         let buffer = create_rect_buffer(
             device,
-            RectUniform {
-                position: [x, y],
-                size: [width, height],
-                radius,
-                _padding: 0.,
+            RectUniform::new(
+                self.display_config.position,
+                self.display_config.size,
+                self.display_config.radii,
+                self.display_config.opacity,
                 resolution,
-            },
+            )
+            .with_clips(&self.clips)
+            .with_transform(self.world),
         );
         create_rect_bind_group(device, layout, &buffer)
     }
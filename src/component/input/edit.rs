@@ -0,0 +1,170 @@
+use winit::{
+    event::{ElementState, Ime},
+    keyboard::{Key, NamedKey},
+};
+
+use crate::{component::Component, event::KeyboardEvent};
+
+use super::{Preedit, TextInput};
+
+impl TextInput {
+    pub(crate) fn handle_key(&mut self, event: &KeyboardEvent) -> bool {
+        // while composing, keys belong to the input method
+        if event.state != ElementState::Pressed || self.preedit.is_some() {
+            return false;
+        }
+        let content = &self.config.content;
+        match &event.key {
+            Key::Named(NamedKey::Backspace) => {
+                if let Some(c) = content[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                    self.config.content.remove(self.cursor);
+                }
+            }
+            Key::Named(NamedKey::Delete) => {
+                if self.cursor < content.len() {
+                    self.config.content.remove(self.cursor);
+                }
+            }
+            Key::Named(NamedKey::ArrowLeft) => {
+                if let Some(c) = content[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                }
+            }
+            Key::Named(NamedKey::ArrowRight) => {
+                if let Some(c) = content[self.cursor..].chars().next() {
+                    self.cursor += c.len_utf8();
+                }
+            }
+            Key::Named(NamedKey::Home) => self.cursor = 0,
+            Key::Named(NamedKey::End) => self.cursor = content.len(),
+            _ => {
+                let text: String = event
+                    .text
+                    .as_ref()
+                    .map(|text| text.chars().filter(|c| !c.is_control()).collect())
+                    .unwrap_or_default();
+                if text.is_empty() {
+                    return false;
+                }
+                self.insert(&text);
+            }
+        }
+        self.sync_content();
+        true
+    }
+
+    pub(crate) fn handle_ime(&mut self, ime: &Ime) -> bool {
+        match ime {
+            Ime::Enabled => return false,
+            Ime::Preedit(content, cursor) => {
+                self.preedit = match content.is_empty() {
+                    true => None,
+                    false => Some(Preedit {
+                        content: content.clone(),
+                        cursor: *cursor,
+                    }),
+                };
+            }
+            Ime::Commit(content) => {
+                self.preedit = None;
+                self.insert(content);
+            }
+            Ime::Disabled => self.preedit = None,
+        }
+        self.sync_content();
+        true
+    }
+
+    pub(crate) fn insert(&mut self, text: &str) {
+        self.config.content.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    /// committed content with the preedit text spliced in at the cursor
+    fn display_content(&self) -> String {
+        let mut content = self.config.content.clone();
+        if let Some(preedit) = &self.preedit {
+            content.insert_str(self.cursor, &preedit.content);
+        }
+        content
+    }
+
+    pub(crate) fn sync_content(&mut self) {
+        let content = self.display_content();
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.set_content(&content);
+        }
+    }
+
+    /// x offset in pixels of the glyph boundary at byte `index` of the displayed text
+    fn offset_of(&self, index: usize) -> f32 {
+        let Some(run) = self
+            .renderer
+            .as_ref()
+            .and_then(|renderer| renderer.buffer().layout_runs().next())
+        else {
+            return 0.;
+        };
+        run.glyphs
+            .iter()
+            .find(|glyph| glyph.start >= index)
+            .map(|glyph| glyph.x)
+            .or_else(|| run.glyphs.last().map(|glyph| glyph.x + glyph.w))
+            .unwrap_or(0.)
+    }
+
+    pub(crate) fn line_top(&self) -> f32 {
+        self.renderer
+            .as_ref()
+            .and_then(|renderer| renderer.buffer().layout_runs().next())
+            .map(|run| run.line_top)
+            .unwrap_or(0.)
+    }
+
+    /// pixel `(x, width)` of the caret, `None` when the input method hides it
+    pub(crate) fn caret_span(&self) -> Option<(f32, f32)> {
+        let index = match &self.preedit {
+            None => self.cursor,
+            Some(preedit) => self.cursor + preedit.cursor?.0,
+        };
+        Some((self.offset_of(index), 0.))
+    }
+
+    /// pixel `(x, width)` of the composing text
+    pub(crate) fn preedit_span(&self) -> Option<(f32, f32)> {
+        let preedit = self.preedit.as_ref()?;
+        let start = self.offset_of(self.cursor);
+        let end = self.offset_of(self.cursor + preedit.content.len());
+        Some((start, end - start))
+    }
+
+    pub(crate) fn layout_decorations(&mut self, config: &wgpu::SurfaceConfiguration) {
+        let (width, height) = (config.width as f32, config.height as f32);
+        let to_ndc = |x: f32, y: f32| (x / width * 2. - 1., 1. - y / height * 2.);
+        let text_config = self.config.fit_screen(config);
+        let line_height = self
+            .renderer
+            .as_ref()
+            .unwrap()
+            .buffer()
+            .metrics()
+            .line_height;
+        let scale = text_config.scale;
+        let top = text_config.top + self.line_top() * scale;
+        if let Some((x, _)) = self.caret_span() {
+            self.caret
+                .set_position(to_ndc(text_config.left + x * scale, top));
+            self.caret
+                .set_size((2. / width * 2., line_height * scale / height * 2.));
+        }
+        if let Some((x, span)) = self.preedit_span() {
+            self.underline.set_position(to_ndc(
+                text_config.left + x * scale,
+                top + (line_height - 2.) * scale,
+            ));
+            self.underline
+                .set_size((span * scale / width * 2., 2. * scale / height * 2.));
+        }
+    }
+}
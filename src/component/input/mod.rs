@@ -0,0 +1,258 @@
+mod api;
+mod edit;
+
+use winit::event::Ime;
+
+use crate::event::KeyboardEvent;
+
+use super::{
+    text::{ClipMask, TextConfig, TextRenderer},
+    Affine, Clip, Component, Handlers, IntoComponent, Rect, Transform, Transitions,
+};
+
+struct Preedit {
+    content: String,
+    cursor: Option<(usize, usize)>,
+}
+
+pub struct TextInput {
+    renderer: Option<TextRenderer>,
+    config: TextConfig,
+    cursor: usize,
+    preedit: Option<Preedit>,
+    focused: bool,
+    caret: Rect,
+    underline: Rect,
+    mask: ClipMask,
+    depth: i32,
+    id: isize,
+    size: (f32, f32),
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            renderer: None,
+            config: TextConfig::default(),
+            cursor: 0,
+            preedit: None,
+            focused: false,
+            caret: Rect::new().color(1., 1., 1., 1.),
+            underline: Rect::new().color(1., 1., 1., 1.),
+            mask: ClipMask::default(),
+            depth: 0,
+            id: 0,
+            size: (0., 0.),
+            handlers: Handlers::default(),
+            transitions: Transitions::default(),
+            hidden: false,
+            focusable: true,
+            draggable: false,
+            transform: Transform::default(),
+        }
+    }
+}
+
+impl Component for TextInput {
+    fn init_layout(&mut self, config: &wgpu::SurfaceConfiguration) {
+        self.renderer = Some(TextRenderer::new(config, &self.config));
+    }
+
+    fn init(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.renderer.as_mut().unwrap().init(device, queue);
+        self.caret.init(device, queue, config);
+        self.underline.init(device, queue, config);
+        self.mask.init(device, config);
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.renderer
+            .as_mut()
+            .unwrap()
+            .prepare(device, queue, config, &self.config);
+        self.layout_decorations(config);
+    }
+
+    fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        let show_caret = self.focused && self.caret_span().is_some();
+        let show_underline = self.preedit_span().is_some();
+        self.mask.render(device, config, render_pass);
+        self.renderer.as_ref().unwrap().render(render_pass);
+        if show_caret {
+            self.caret.render(device, config, render_pass);
+        }
+        if show_underline {
+            self.underline.render(device, config, render_pass);
+        }
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.mask.set_clips(clips);
+        self.caret.set_clips(clips);
+        self.underline.set_clips(clips);
+    }
+
+    fn clean(&mut self) {
+        self.renderer.as_mut().unwrap().trim();
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        (self.config.left, self.config.top)
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.config.left = position.0;
+        self.config.top = position.1;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.size = size;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, config: &wgpu::SurfaceConfiguration) {
+        self.config.follow(transform, config);
+    }
+
+    fn set_focus(&mut self, focus: bool) {
+        self.focused = focus;
+        if !focus && self.preedit.take().is_some() {
+            self.sync_content();
+        }
+    }
+
+    fn ime_allowed(&self) -> bool {
+        true
+    }
+
+    fn keyboard_input(&mut self, event: &KeyboardEvent) -> bool {
+        self.handle_key(event)
+    }
+
+    fn ime(&mut self, ime: &Ime) -> bool {
+        self.handle_ime(ime)
+    }
+
+    fn paste(&mut self, content: &str) -> bool {
+        if self.preedit.is_some() {
+            return false;
+        }
+        let content: String = content.chars().filter(|c| !c.is_control()).collect();
+        self.insert(&content);
+        self.sync_content();
+        true
+    }
+
+    fn ime_cursor_area(
+        &self,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Option<((f32, f32), (f32, f32))> {
+        let (x, _) = self.caret_span().or_else(|| self.preedit_span())?;
+        let text_config = self.config.fit_screen(config);
+        let scale = text_config.scale;
+        let metrics = self.renderer.as_ref()?.buffer().metrics();
+        Some((
+            (
+                text_config.left + x * scale,
+                text_config.top + self.line_top() * scale,
+            ),
+            (metrics.font_size * scale, metrics.line_height * scale),
+        ))
+    }
+}
+
+impl IntoComponent for TextInput {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::TextInput(self)
+    }
+}
@@ -0,0 +1,65 @@
+use super::{Fill, Line, LineCap};
+
+impl Line {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// starts the line at `x`, `y` in fractions of the window from its top left corner
+    pub fn from(mut self, x: f32, y: f32) -> Self {
+        let (_, to) = self.points();
+        self.set_points((x * 2. - 1., -(y * 2. - 1.)), to);
+        self
+    }
+
+    /// ends the line at `x`, `y` in fractions of the window from its top left corner
+    pub fn to(mut self, x: f32, y: f32) -> Self {
+        let (from, _) = self.points();
+        self.set_points(from, (x * 2. - 1., -(y * 2. - 1.)));
+        self
+    }
+
+    /// width of the line in physical pixels
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.display_config.thickness = thickness;
+        self
+    }
+
+    pub fn cap(mut self, cap: LineCap) -> Self {
+        self.display_config.cap = cap;
+        self
+    }
+
+    pub fn color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.fill = Fill::Solid((r, g, b, a));
+        self
+    }
+
+    /// a solid color or a gradient over the bounds, replacing [`Line::color`]
+    pub fn fill(mut self, fill: Fill) -> Self {
+        self.display_config.fill = fill;
+        self
+    }
+
+    /// outlines the line `width` physical pixels wide, centered on its edge
+    pub fn stroke(mut self, width: f32) -> Self {
+        self.display_config.stroke_width = width;
+        self
+    }
+
+    pub fn stroke_color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.stroke_color = (r, g, b, a);
+        self
+    }
+
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// from 0 for fully transparent to 1
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.display_config.opacity = opacity;
+        self
+    }
+}
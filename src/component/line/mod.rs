@@ -0,0 +1,246 @@
+mod api;
+
+use super::{
+    shape::{self, LineCap, ShapeKind, ShapeRenderer, ShapeUniform},
+    Affine, Clip, Component, Fill, Handlers, IntoComponent, RectUniform, Transform, Transitions,
+};
+
+struct DisplayConfig {
+    /// the box spanned by the end points
+    size: (f32, f32),
+    position: (f32, f32),
+    /// whether the line starts at the right and bottom edges of its box instead of the left and
+    /// top ones
+    flip: (bool, bool),
+    fill: Fill,
+    /// in physical pixels
+    thickness: f32,
+    cap: LineCap,
+    /// in physical pixels, centered on the outline
+    stroke_width: f32,
+    stroke_color: (f32, f32, f32, f32),
+    opacity: f32,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            size: (2., 2.),
+            position: (-1., 1.),
+            flip: (false, false),
+            fill: Fill::solid(0., 0., 1., 1.),
+            thickness: 2.,
+            cap: LineCap::default(),
+            stroke_width: 0.,
+            stroke_color: (0., 0., 0., 1.),
+            opacity: 1.,
+        }
+    }
+}
+
+/// A straight line between two points with a thickness and caps, optionally outlined by a
+/// stroke.
+#[derive(Default)]
+pub struct Line {
+    display_config: DisplayConfig,
+    renderer: ShapeRenderer,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    world: Affine,
+    clips: Vec<Clip>,
+    depth: i32,
+    id: isize,
+}
+
+impl Component for Line {
+    fn init(
+        &mut self,
+        device: &wgpu::Device,
+        _queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.renderer.init(device, config);
+    }
+
+    fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        let DisplayConfig {
+            size,
+            position,
+            ref fill,
+            thickness,
+            cap,
+            stroke_width,
+            stroke_color,
+            opacity,
+            ..
+        } = self.display_config;
+        let (from, to) = self.points();
+        let resolution = [config.width as f32, config.height as f32];
+        let rect = RectUniform::new(position, size, [0.; 4], opacity, resolution)
+            .with_clips(&self.clips)
+            .with_transform(self.world);
+        let shape = ShapeUniform::new(ShapeKind::Line, stroke_width, stroke_color)
+            .with_line(from, to, thickness, cap);
+        // square caps reach furthest, diagonally past the end points
+        let margin = thickness / 2. * std::f32::consts::SQRT_2 + stroke_width / 2. + 1.;
+        let corners = shape::quad(position, size, margin, self.world, config);
+        self.renderer
+            .render(device, render_pass, rect, shape, fill, corners);
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.display_config.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.display_config.size = size;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        self.display_config.position
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.display_config.position = position;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn contains(&self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        let (from, to) = self.points();
+        shape::segment_sdf(
+            shape::pixels(cursor, config),
+            shape::pixels(from, config),
+            shape::pixels(to, config),
+            self.display_config.thickness,
+            self.display_config.cap,
+        ) <= self.display_config.stroke_width / 2.
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, _config: &wgpu::SurfaceConfiguration) {
+        self.world = transform;
+    }
+
+    fn get_color(&self) -> Option<(f32, f32, f32, f32)> {
+        self.display_config.fill.color()
+    }
+
+    fn set_color(&mut self, color: (f32, f32, f32, f32)) {
+        self.display_config.fill = Fill::Solid(color);
+    }
+
+    fn get_opacity(&self) -> Option<f32> {
+        Some(self.display_config.opacity)
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.display_config.opacity = opacity;
+    }
+}
+
+impl Line {
+    /// the end points in screen space
+    fn points(&self) -> ((f32, f32), (f32, f32)) {
+        let (x, y) = self.display_config.position;
+        let (width, height) = self.display_config.size;
+        let (flip_x, flip_y) = self.display_config.flip;
+        let (from_x, to_x) = match flip_x {
+            true => (x + width, x),
+            false => (x, x + width),
+        };
+        let (from_y, to_y) = match flip_y {
+            true => (y - height, y),
+            false => (y, y - height),
+        };
+        ((from_x, from_y), (to_x, to_y))
+    }
+
+    fn set_points(&mut self, from: (f32, f32), to: (f32, f32)) {
+        self.display_config.position = (from.0.min(to.0), from.1.max(to.1));
+        self.display_config.size = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+        self.display_config.flip = (from.0 > to.0, from.1 < to.1);
+    }
+}
+
+impl IntoComponent for Line {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::Line(self)
+    }
+}
@@ -1,30 +1,88 @@
+mod camera;
 mod common;
 mod container;
+mod ellipse;
+mod fill;
+mod focus_ring;
+mod handler;
 mod img;
+mod input;
+mod line;
+mod path;
 mod rect;
+mod scroll_view;
+mod shape;
 mod text;
+mod textarea;
+mod transform;
 
+pub use camera::Camera;
+pub(crate) use common::{create_stencil_view, Clip, MAX_CLIPS};
+pub use common::{BorderAlign, Overflow};
 pub use container::Container;
+pub use ellipse::Ellipse;
 use enum_dispatch::enum_dispatch;
+pub(crate) use fill::create_fill_buffer;
+pub use fill::{ColorStop, Fill};
+pub(crate) use focus_ring::FocusRing;
+pub(crate) use handler::Handler;
+pub use handler::Handlers;
 pub use img::Img;
+pub use input::TextInput;
+pub use line::Line;
+pub use path::{FillRule, LineJoin, Path};
 pub use rect::Rect;
+pub(crate) use rect::RectUniform;
+pub(crate) use scroll_view::Axis;
+pub use scroll_view::ScrollView;
+pub use shape::LineCap;
+use std::time::Duration;
 pub use text::Text;
+pub use textarea::TextArea;
+pub(crate) use transform::Affine;
+pub use transform::Transform;
+use winit::event::Ime;
 
-use crate::context::Context;
+use crate::{
+    animation::{Easing, Property, Transitions},
+    context::Context,
+    event::{DragEvent, FileDropEvent, GestureEvent, KeyboardEvent, PointerEvent, ScrollEvent},
+};
+
+use self::handler::handler;
 
 pub trait IntoComponent {
     fn into_comp(self) -> Comp;
 }
 
+/// lets a view pick between components of different kinds
+impl IntoComponent for Comp {
+    fn into_comp(self) -> Comp {
+        self
+    }
+}
+
 #[enum_dispatch(Component)]
+#[allow(clippy::large_enum_variant)]
 pub enum Comp {
     Rect(Rect),
     Img(Img),
     Text(Text),
+    TextInput(TextInput),
+    TextArea(TextArea),
+    ScrollView(ScrollView),
+    Camera(Camera),
+    Ellipse(Ellipse),
+    Line(Line),
+    Path(Path),
 }
 
 #[enum_dispatch]
 pub trait Component: 'static + Sized {
+    /// sets up what the component needs for layout and input, called before [`Component::init`]
+    /// and also when running without a GPU
+    fn init_layout(&mut self, _config: &wgpu::SurfaceConfiguration) {}
+
     fn init(
         &mut self,
         _device: &wgpu::Device,
@@ -51,6 +109,12 @@ pub trait Component: 'static + Sized {
     {
     }
 
+    /// inner method, whether the component is drawn right after its last descendant instead of
+    /// before them, for decorations over the children such as scrollbars
+    fn draws_over_children(&self) -> bool {
+        false
+    }
+
     fn clean(&mut self) {}
 
     fn children(&mut self) -> Option<&mut Components> {
@@ -73,6 +137,60 @@ pub trait Component: 'static + Sized {
 
     fn set_id(&mut self, id: isize);
 
+    fn handlers(&self) -> &Handlers;
+
+    fn hidden(&self) -> bool;
+
+    fn set_hidden(&mut self, hidden: bool);
+
+    fn is_focusable(&self) -> bool;
+
+    fn set_focusable(&mut self, focusable: bool);
+
+    fn is_draggable(&self) -> bool;
+
+    fn set_draggable(&mut self, draggable: bool);
+
+    fn get_transform(&self) -> Transform;
+
+    fn set_transform(&mut self, transform: Transform);
+
+    fn handlers_mut(&mut self) -> &mut Handlers;
+
+    fn transitions(&self) -> &Transitions;
+
+    fn transitions_mut(&mut self) -> &mut Transitions;
+
+    /// hidden components and their children are neither drawn nor hit by the pointer
+    #[allow(unused_mut)]
+    fn visible(mut self, visible: bool) -> Self {
+        self.set_hidden(!visible);
+        self
+    }
+
+    /// focusable components receive keyboard events and can be reached with Tab and Shift+Tab
+    #[allow(unused_mut)]
+    fn focusable(mut self, focusable: bool) -> Self {
+        self.set_focusable(focusable);
+        self
+    }
+
+    /// animates changes of `property` made through the setters of [`Context`], such as
+    /// [`Context::set_color`], or by a new view from [`Context::set_view`], starting from the
+    /// value shown at the time of the change
+    #[allow(unused_mut)]
+    fn transition(mut self, property: Property, duration: Duration, easing: Easing) -> Self {
+        self.transitions_mut().set(property, duration, easing);
+        self
+    }
+
+    /// rotates, scales, skews or moves the component and its children, see [`Transform`]
+    #[allow(unused_mut)]
+    fn transform(mut self, transform: Transform) -> Self {
+        self.set_transform(transform);
+        self
+    }
+
     #[allow(unused_mut)]
     fn center(mut self) -> Self {
         let size = self.get_size();
@@ -96,6 +214,180 @@ pub trait Component: 'static + Sized {
         self
     }
 
+    /// called when the left button is pressed and released over the component
+    #[allow(unused_mut)]
+    fn on_click(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().click = handler(f);
+        self
+    }
+
+    /// called when the right button is pressed and released over the component
+    #[allow(unused_mut)]
+    fn on_right_click(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().right_click = handler(f);
+        self
+    }
+
+    /// called when the middle button is pressed and released over the component
+    #[allow(unused_mut)]
+    fn on_middle_click(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().middle_click = handler(f);
+        self
+    }
+
+    /// called after the second of two left clicks in quick succession, see
+    /// [`crate::PointerConfig`]
+    #[allow(unused_mut)]
+    fn on_double_click(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().double_click = handler(f);
+        self
+    }
+
+    /// called when the left button is held down over the component without moving, the
+    /// release that follows is not a click
+    #[allow(unused_mut)]
+    fn on_long_press(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().long_press = handler(f);
+        self
+    }
+
+    /// called when any button is pressed over the component
+    #[allow(unused_mut)]
+    fn on_mouse_down(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().mouse_down = handler(f);
+        self
+    }
+
+    /// called when any button is released over the component
+    #[allow(unused_mut)]
+    fn on_mouse_up(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().mouse_up = handler(f);
+        self
+    }
+
+    #[allow(unused_mut)]
+    fn on_mouse_enter(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().mouse_enter = handler(f);
+        self
+    }
+
+    #[allow(unused_mut)]
+    fn on_mouse_leave(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().mouse_leave = handler(f);
+        self
+    }
+
+    #[allow(unused_mut)]
+    fn on_mouse_move(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().mouse_move = handler(f);
+        self
+    }
+
+    #[allow(unused_mut)]
+    fn on_scroll(mut self, f: impl FnMut(Context, &mut ScrollEvent) + 'static) -> Self {
+        self.handlers_mut().scroll = handler(f);
+        self
+    }
+
+    /// called for every pointer event on the way down to a descendant, before the descendant's
+    /// own handlers run, see [`crate::Phase`]
+    #[allow(unused_mut)]
+    fn on_pointer_capture(mut self, f: impl FnMut(Context, &mut PointerEvent) + 'static) -> Self {
+        self.handlers_mut().pointer_capture = handler(f);
+        self
+    }
+
+    /// called for scroll events on the way down to a descendant, before the descendant's own
+    /// handlers run
+    #[allow(unused_mut)]
+    fn on_scroll_capture(mut self, f: impl FnMut(Context, &mut ScrollEvent) + 'static) -> Self {
+        self.handlers_mut().scroll_capture = handler(f);
+        self
+    }
+
+    /// called when a key is pressed while the component or one of its descendants is focused
+    #[allow(unused_mut)]
+    fn on_key_down(mut self, f: impl FnMut(Context, &mut KeyboardEvent) + 'static) -> Self {
+        self.handlers_mut().key_down = handler(f);
+        self
+    }
+
+    /// called when a key is released while the component or one of its descendants is focused
+    #[allow(unused_mut)]
+    fn on_key_up(mut self, f: impl FnMut(Context, &mut KeyboardEvent) + 'static) -> Self {
+        self.handlers_mut().key_up = handler(f);
+        self
+    }
+
+    /// called for keyboard events on the way down to the focused descendant, before its own
+    /// handlers run
+    #[allow(unused_mut)]
+    fn on_key_capture(mut self, f: impl FnMut(Context, &mut KeyboardEvent) + 'static) -> Self {
+        self.handlers_mut().key_capture = handler(f);
+        self
+    }
+
+    /// lets the component and its children be picked up and moved with the left button
+    #[allow(unused_mut)]
+    fn draggable(mut self) -> Self {
+        self.set_draggable(true);
+        self
+    }
+
+    /// called when the pointer has moved far enough with the component pressed to start a drag,
+    /// [`DragEvent::prevent_default`] cancels it
+    #[allow(unused_mut)]
+    fn on_drag_start(mut self, f: impl FnMut(Context, &mut DragEvent) + 'static) -> Self {
+        self.handlers_mut().drag_start = handler(f);
+        self
+    }
+
+    /// called while the component or one of its children is dragged
+    #[allow(unused_mut)]
+    fn on_drag(mut self, f: impl FnMut(Context, &mut DragEvent) + 'static) -> Self {
+        self.handlers_mut().drag = handler(f);
+        self
+    }
+
+    /// makes the component a drop target, called when a dragged component is released over it
+    ///
+    /// The dragged component returns to where it was picked up; move it from here if it should
+    /// stay where it was dropped.
+    #[allow(unused_mut)]
+    fn on_drop(mut self, f: impl FnMut(Context, &mut DragEvent) + 'static) -> Self {
+        self.handlers_mut().drop = handler(f);
+        self
+    }
+
+    /// accepts files dragged in from outside the window, which highlight the component while
+    /// hovering over it
+    #[allow(unused_mut)]
+    fn on_file_drop(mut self, f: impl FnMut(Context, &mut FileDropEvent) + 'static) -> Self {
+        self.handlers_mut().file_drop = handler(f);
+        self
+    }
+
+    /// called while two fingers move apart or together over the component
+    #[allow(unused_mut)]
+    fn on_pinch(mut self, f: impl FnMut(Context, &mut GestureEvent) + 'static) -> Self {
+        self.handlers_mut().pinch = handler(f);
+        self
+    }
+
+    /// called while two fingers move together over the component
+    #[allow(unused_mut)]
+    fn on_pan(mut self, f: impl FnMut(Context, &mut GestureEvent) + 'static) -> Self {
+        self.handlers_mut().pan = handler(f);
+        self
+    }
+
+    /// called while two fingers turn around each other over the component
+    #[allow(unused_mut)]
+    fn on_rotate(mut self, f: impl FnMut(Context, &mut GestureEvent) + 'static) -> Self {
+        self.handlers_mut().rotate = handler(f);
+        self
+    }
+
     /// inner method
     fn apply_workspace(&mut self, size: (f32, f32), offset: (f32, f32)) {
         let position = self.get_position();
@@ -112,20 +404,74 @@ pub trait Component: 'static + Sized {
         }
     }
 
+    /// inner method, whether the screen space `cursor` falls on the shape of the component
+    fn contains(&self, cursor: (f32, f32), _config: &wgpu::SurfaceConfiguration) -> bool {
+        let position = self.get_position();
+        let size = self.get_size();
+        cursor.0 >= position.0
+            && cursor.0 < position.0 + size.0
+            && cursor.1 <= position.1
+            && cursor.1 > position.1 - size.1
+    }
+
+    /// inner method, the transform applied to the children on top of the transform of the
+    /// component, for a component with `bounds` in physical pixels
+    fn children_transform(&self, _bounds: (f32, f32, f32, f32)) -> Affine {
+        Affine::IDENTITY
+    }
+
+    /// inner method, whether children are cut off outside the shape of this component
+    fn clips_children(&self) -> bool {
+        false
+    }
+
+    /// inner method, the radii of the top left, top right, bottom right and bottom left corners
+    /// children are rounded to when clipped
+    fn corner_radii(&self) -> [f32; 4] {
+        [0.; 4]
+    }
+
+    /// inner method, `None` for components without a color
+    fn get_color(&self) -> Option<(f32, f32, f32, f32)> {
+        None
+    }
+
     /// inner method
-    fn collect_and_init(
-        &mut self,
-        device: &wgpu::Device,
-        queue: &wgpu::Queue,
-        config: &wgpu::SurfaceConfiguration,
-    ) -> Components {
+    fn set_color(&mut self, _color: (f32, f32, f32, f32)) {}
+
+    /// inner method
+    fn set_corner_radii(&mut self, _radii: [f32; 4]) {}
+
+    /// inner method, `None` for components that are always opaque
+    fn get_opacity(&self) -> Option<f32> {
+        None
+    }
+
+    /// inner method
+    fn set_opacity(&mut self, _opacity: f32) {}
+
+    /// inner method, the ancestors that cut off this component along a rounded or transformed
+    /// outline, innermost first
+    fn set_clips(&mut self, _clips: &[Clip]) {}
+
+    /// inner method, the transforms of the component and its ancestors combined, in physical
+    /// pixels
+    fn set_world_transform(&mut self, _transform: Affine, _config: &wgpu::SurfaceConfiguration) {}
+
+    /// inner method, flattens the subtree in pre-order, pairing each component with the index
+    /// of its parent in the returned list, `None` for direct children of `self`
+    fn collect(&mut self) -> Vec<(Comp, Option<usize>)> {
         let mut r = vec![];
         let children = self.take_children();
         for mut child in children {
-            child.init(device, queue, config);
-            let mut children_components = child.collect_and_init(device, queue, config);
-            r.push(child);
-            r.append(&mut children_components);
+            let children_components = child.collect();
+            let index = r.len();
+            r.push((child, None));
+            r.extend(
+                children_components
+                    .into_iter()
+                    .map(|(comp, parent)| (comp, Some(parent.map_or(index, |p| index + 1 + p)))),
+            );
         }
         r
     }
@@ -139,7 +485,66 @@ pub trait Component: 'static + Sized {
     }
 
     /// inner method
-    fn click_handler(&self) -> Option<fn(Context)> {
+    fn set_focus(&mut self, _focus: bool) {}
+
+    /// inner method, whether the input method should be enabled while focused
+    fn ime_allowed(&self) -> bool {
+        false
+    }
+
+    /// inner method, returns true if the event was consumed
+    fn keyboard_input(&mut self, _event: &KeyboardEvent) -> bool {
+        false
+    }
+
+    /// inner method, returns true if the event was consumed
+    fn ime(&mut self, _ime: &Ime) -> bool {
+        false
+    }
+
+    /// inner method, returns the selected text
+    fn copy(&self) -> Option<String> {
+        None
+    }
+
+    /// inner method, removes and returns the selected text
+    fn cut(&mut self) -> Option<String> {
+        None
+    }
+
+    /// inner method, returns true if the text was accepted
+    fn paste(&mut self, _content: &str) -> bool {
+        false
+    }
+
+    /// inner method, called with the cursor in screen space when the left button is pressed on
+    /// this component, `click_count` times in quick succession, returns true if a redraw is
+    /// needed
+    fn mouse_down(
+        &mut self,
+        _cursor: (f32, f32),
+        _click_count: u32,
+        _config: &wgpu::SurfaceConfiguration,
+    ) -> bool {
+        false
+    }
+
+    /// inner method, called while the left button pressed on this component is held down
+    fn mouse_drag(&mut self, _cursor: (f32, f32), _config: &wgpu::SurfaceConfiguration) -> bool {
+        false
+    }
+
+    /// inner method, called with the wheel movement in physical pixels while the pointer is over
+    /// this component, returns true if the component scrolled
+    fn wheel(&mut self, _delta: (f32, f32)) -> bool {
+        false
+    }
+
+    /// inner method, returns the position and size of the caret in physical pixels
+    fn ime_cursor_area(
+        &self,
+        _config: &wgpu::SurfaceConfiguration,
+    ) -> Option<((f32, f32), (f32, f32))> {
         None
     }
 }
@@ -0,0 +1,150 @@
+use super::{Command, Fill, FillRule, LineCap, LineJoin, Path};
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// appends the commands of the SVG path data `d`, such as `"M 0 0 L 10 0 Q 10 10 0 10 Z"`
+    pub fn svg(mut self, d: &str) -> anyhow::Result<Self> {
+        self.commands.extend(super::geometry::parse_svg(d)?);
+        Ok(self)
+    }
+
+    /// starts a new outline at `x`, `y`
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.commands.push(Command::MoveTo(x, y));
+        self
+    }
+
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.commands.push(Command::LineTo(x, y));
+        self
+    }
+
+    /// a quadratic Bézier curve pulled towards `cx`, `cy`
+    pub fn quad_to(mut self, cx: f32, cy: f32, x: f32, y: f32) -> Self {
+        self.commands.push(Command::QuadTo {
+            ctrl: (cx, cy),
+            to: (x, y),
+        });
+        self
+    }
+
+    /// a cubic Bézier curve leaving towards `c1x`, `c1y` and arriving from `c2x`, `c2y`
+    pub fn cubic_to(mut self, c1x: f32, c1y: f32, c2x: f32, c2y: f32, x: f32, y: f32) -> Self {
+        self.commands.push(Command::CubicTo {
+            ctrl1: (c1x, c1y),
+            ctrl2: (c2x, c2y),
+            to: (x, y),
+        });
+        self
+    }
+
+    /// an elliptical arc with radii `rx`, `ry` turned by `rotation` radians, like the SVG `A`
+    /// command, the flags pick one of the four arcs through both points
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        mut self,
+        rx: f32,
+        ry: f32,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        x: f32,
+        y: f32,
+    ) -> Self {
+        self.commands.push(Command::ArcTo {
+            radii: (rx, ry),
+            rotation,
+            large_arc,
+            sweep,
+            to: (x, y),
+        });
+        self
+    }
+
+    /// connects the outline back to where it started
+    pub fn close(mut self) -> Self {
+        self.commands.push(Command::Close);
+        self
+    }
+
+    /// the area of path coordinates fitted into the bounds, the bounding box of the path by
+    /// default
+    pub fn view_box(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.view_box = Some((x, y, width, height));
+        self
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.display_config.size = (width * 2., height * 2.);
+        self
+    }
+
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.display_config.position = (x * 2. - 1., -(y * 2. - 1.));
+        self
+    }
+
+    /// the fill, transparent for an outline only
+    pub fn color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.fill = Fill::Solid((r, g, b, a));
+        self
+    }
+
+    /// a solid color or a gradient over the bounds, replacing [`Path::color`]
+    pub fn fill(mut self, fill: Fill) -> Self {
+        self.display_config.fill = fill;
+        self
+    }
+
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.display_config.fill_rule = fill_rule;
+        self
+    }
+
+    /// draws the outline `width` physical pixels wide, centered on the path
+    pub fn stroke(mut self, width: f32) -> Self {
+        self.stroke.width = width;
+        self
+    }
+
+    pub fn stroke_color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.stroke_color = (r, g, b, a);
+        self
+    }
+
+    pub fn line_join(mut self, join: LineJoin) -> Self {
+        self.stroke.join = join;
+        self
+    }
+
+    pub fn line_cap(mut self, cap: LineCap) -> Self {
+        self.stroke.cap = cap;
+        self
+    }
+
+    /// dashes the stroke with alternating dash and gap lengths in physical pixels
+    pub fn dashes(mut self, dashes: &[f32]) -> Self {
+        self.stroke.dashes = dashes.to_vec();
+        self
+    }
+
+    /// how far into the dash pattern the stroke starts, in physical pixels
+    pub fn dash_offset(mut self, offset: f32) -> Self {
+        self.stroke.dash_offset = offset;
+        self
+    }
+
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// from 0 for fully transparent to 1
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.display_config.opacity = opacity;
+        self
+    }
+}
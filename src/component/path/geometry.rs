@@ -0,0 +1,267 @@
+use lyon::{
+    algorithms::{
+        aabb::bounding_box,
+        measure::{PathMeasurements, SampleType},
+    },
+    extra::parser::{ParserOptions, PathParser, Source},
+    geom::{euclid::Transform2D, Angle, ArcFlags},
+    math::{point, vector, Point},
+    path::{builder::SvgPathBuilder, Path as LyonPath, PathEvent},
+    tessellation::{
+        BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, StrokeTessellator,
+        StrokeVertex, VertexBuffers,
+    },
+};
+
+use super::{FillRule, LineCap, LineJoin};
+
+/// how far in physical pixels curves may stray from their flattened triangles
+const TOLERANCE: f32 = 0.25;
+
+/// One step of a path, in the coordinates of its view box with y pointing down.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Command {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    QuadTo {
+        ctrl: (f32, f32),
+        to: (f32, f32),
+    },
+    CubicTo {
+        ctrl1: (f32, f32),
+        ctrl2: (f32, f32),
+        to: (f32, f32),
+    },
+    ArcTo {
+        radii: (f32, f32),
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: (f32, f32),
+    },
+    Close,
+}
+
+/// the commands of the SVG path data `d`, arcs already turned into curves
+pub(crate) fn parse_svg(d: &str) -> anyhow::Result<Vec<Command>> {
+    let mut builder = LyonPath::builder();
+    PathParser::new().parse(
+        &ParserOptions::DEFAULT,
+        &mut Source::new(d.chars()),
+        &mut builder,
+    )?;
+    let commands = builder
+        .build()
+        .iter()
+        .filter_map(|event| match event {
+            PathEvent::Begin { at } => Some(Command::MoveTo(at.x, at.y)),
+            PathEvent::Line { to, .. } => Some(Command::LineTo(to.x, to.y)),
+            PathEvent::Quadratic { ctrl, to, .. } => Some(Command::QuadTo {
+                ctrl: (ctrl.x, ctrl.y),
+                to: (to.x, to.y),
+            }),
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => Some(Command::CubicTo {
+                ctrl1: (ctrl1.x, ctrl1.y),
+                ctrl2: (ctrl2.x, ctrl2.y),
+                to: (to.x, to.y),
+            }),
+            PathEvent::End { close: true, .. } => Some(Command::Close),
+            PathEvent::End { close: false, .. } => None,
+        })
+        .collect();
+    Ok(commands)
+}
+
+fn build(commands: &[Command]) -> LyonPath {
+    let p = |(x, y): (f32, f32)| point(x, y);
+    let mut builder = LyonPath::svg_builder();
+    for command in commands {
+        match *command {
+            Command::MoveTo(x, y) => {
+                builder.move_to(point(x, y));
+            }
+            Command::LineTo(x, y) => {
+                builder.line_to(point(x, y));
+            }
+            Command::QuadTo { ctrl, to } => {
+                builder.quadratic_bezier_to(p(ctrl), p(to));
+            }
+            Command::CubicTo { ctrl1, ctrl2, to } => {
+                builder.cubic_bezier_to(p(ctrl1), p(ctrl2), p(to));
+            }
+            Command::ArcTo {
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => builder.arc_to(
+                vector(radii.0, radii.1),
+                Angle::radians(rotation),
+                ArcFlags { large_arc, sweep },
+                p(to),
+            ),
+            Command::Close => builder.close(),
+        }
+    }
+    builder.build()
+}
+
+/// The stroke of a path in physical pixels.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Stroke {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    /// lengths of the dashes and the gaps between them, alternating, solid if empty
+    pub dashes: Vec<f32>,
+    pub dash_offset: f32,
+}
+
+/// A vertex of the tessellated path in physical pixels before the transforms.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GeometryVertex {
+    pub position: [f32; 2],
+    /// whether the vertex belongs to the stroke rather than the fill
+    pub stroke: bool,
+}
+
+/// The triangles of a path laid out in `bounds`, fill first and stroke on top.
+pub(crate) struct Geometry {
+    /// left, top, width and height in physical pixels the path was fitted into
+    pub bounds: (f32, f32, f32, f32),
+    pub filled: bool,
+    pub vertices: Vec<GeometryVertex>,
+    pub indices: Vec<u32>,
+}
+
+impl Geometry {
+    /// tessellates `commands`, scaling `view_box`, or the bounding box of the path if there is
+    /// none, to fit `bounds` centered without distorting it
+    pub fn new(
+        commands: &[Command],
+        view_box: Option<(f32, f32, f32, f32)>,
+        bounds: (f32, f32, f32, f32),
+        fill: Option<FillRule>,
+        stroke: &Stroke,
+    ) -> Self {
+        let mut buffers: VertexBuffers<GeometryVertex, u32> = VertexBuffers::new();
+        let path = build(commands);
+        let (x, y, width, height) = view_box.unwrap_or_else(|| {
+            let aabb = bounding_box(path.iter());
+            (aabb.min.x, aabb.min.y, aabb.width(), aabb.height())
+        });
+        let scale = match (width > 0., height > 0.) {
+            (true, true) => (bounds.2 / width).min(bounds.3 / height),
+            (true, false) => bounds.2 / width,
+            (false, true) => bounds.3 / height,
+            (false, false) => 1.,
+        };
+        let offset = (
+            bounds.0 + (bounds.2 - width * scale) / 2. - x * scale,
+            bounds.1 + (bounds.3 - height * scale) / 2. - y * scale,
+        );
+        let path = path.transformed(
+            &Transform2D::scale(scale, scale).then_translate(vector(offset.0, offset.1)),
+        );
+        if let Some(rule) = fill {
+            let options = FillOptions::tolerance(TOLERANCE).with_fill_rule(match rule {
+                FillRule::NonZero => lyon::tessellation::FillRule::NonZero,
+                FillRule::EvenOdd => lyon::tessellation::FillRule::EvenOdd,
+            });
+            let mut output = BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+                vertex_at(vertex.position(), false)
+            });
+            if FillTessellator::new()
+                .tessellate_path(&path, &options, &mut output)
+                .is_err()
+            {
+                buffers.clear();
+            }
+        }
+        if stroke.width > 0. {
+            let path = dashed(&path, &stroke.dashes, stroke.dash_offset);
+            let cap = match stroke.cap {
+                LineCap::Butt => lyon::tessellation::LineCap::Butt,
+                LineCap::Round => lyon::tessellation::LineCap::Round,
+                LineCap::Square => lyon::tessellation::LineCap::Square,
+            };
+            let options = StrokeOptions::tolerance(TOLERANCE)
+                .with_line_width(stroke.width)
+                .with_line_cap(cap)
+                .with_line_join(match stroke.join {
+                    LineJoin::Miter => lyon::tessellation::LineJoin::Miter,
+                    LineJoin::Round => lyon::tessellation::LineJoin::Round,
+                    LineJoin::Bevel => lyon::tessellation::LineJoin::Bevel,
+                });
+            let (vertices, indices) = (buffers.vertices.len(), buffers.indices.len());
+            let mut output = BuffersBuilder::new(&mut buffers, |vertex: StrokeVertex| {
+                vertex_at(vertex.position(), true)
+            });
+            if StrokeTessellator::new()
+                .tessellate_path(&path, &options, &mut output)
+                .is_err()
+            {
+                buffers.vertices.truncate(vertices);
+                buffers.indices.truncate(indices);
+            }
+        }
+        Self {
+            bounds,
+            filled: fill.is_some(),
+            vertices: buffers.vertices,
+            indices: buffers.indices,
+        }
+    }
+
+    /// whether `point` in physical pixels falls on one of the triangles
+    pub fn contains(&self, point: (f32, f32)) -> bool {
+        self.indices.chunks_exact(3).any(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| self.vertices[triangle[i] as usize].position);
+            let side = |p: [f32; 2], q: [f32; 2]| {
+                (q[0] - p[0]) * (point.1 - p[1]) - (q[1] - p[1]) * (point.0 - p[0])
+            };
+            let (ab, bc, ca) = (side(a, b), side(b, c), side(c, a));
+            (ab >= 0. && bc >= 0. && ca >= 0.) || (ab <= 0. && bc <= 0. && ca <= 0.)
+        })
+    }
+}
+
+fn vertex_at(position: Point, stroke: bool) -> GeometryVertex {
+    GeometryVertex {
+        position: position.to_array(),
+        stroke,
+    }
+}
+
+/// the pieces of `path` covered by the dash pattern, starting `offset` into the pattern
+fn dashed(path: &LyonPath, dashes: &[f32], offset: f32) -> LyonPath {
+    let period: f32 = dashes.iter().sum();
+    if dashes.iter().any(|dash| *dash < 0.) || period <= 0. {
+        return path.clone();
+    }
+    // tiny dashes would split the path into more pieces than there are pixels to show them
+    let dashes: Vec<f32> = dashes.iter().map(|dash| dash.max(TOLERANCE)).collect();
+    let period: f32 = dashes.iter().sum();
+    let measurements = PathMeasurements::from_path(path, TOLERANCE);
+    let mut sampler = measurements.create_sampler(path, SampleType::Distance);
+    let length = sampler.length();
+    let mut builder = LyonPath::builder();
+    if length <= 0. {
+        return builder.build();
+    }
+    let mut distance = -offset.rem_euclid(period);
+    // odd patterns repeat with dashes and gaps swapped, as in SVG
+    for (index, dash) in dashes.iter().cycle().enumerate() {
+        if distance >= length {
+            break;
+        }
+        if index % 2 == 0 {
+            sampler.split_range(distance.max(0.)..distance + dash, &mut builder);
+        }
+        distance += dash;
+    }
+    builder.build()
+}
@@ -0,0 +1,331 @@
+mod api;
+mod bind_group;
+mod geometry;
+mod vertex;
+
+use std::cell::RefCell;
+
+use crate::vertex::Vertex;
+
+use self::{
+    bind_group::{create_bind_group, create_bind_group_layout, create_rect_buffer},
+    geometry::{Command, Geometry, Stroke},
+    vertex::PathVertex,
+};
+
+use super::{
+    common, create_fill_buffer, shape, Affine, Clip, Component, Fill, Handlers, IntoComponent,
+    LineCap, RectUniform, Transform, Transitions,
+};
+
+/// Which parts of a self intersecting [`Path`] are inside.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// everything the outline winds around, unless it winds both ways equally often
+    #[default]
+    NonZero,
+    /// everything enclosed an odd number of times, overlaps become holes
+    EvenOdd,
+}
+
+/// How the segments of a stroked [`Path`] meet at corners.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineJoin {
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+struct DisplayConfig {
+    size: (f32, f32),
+    position: (f32, f32),
+    fill: Fill,
+    fill_rule: FillRule,
+    stroke_color: (f32, f32, f32, f32),
+    opacity: f32,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            size: (2., 2.),
+            position: (-1., 1.),
+            fill: Fill::solid(0., 0., 1., 1.),
+            fill_rule: FillRule::default(),
+            stroke_color: (0., 0., 0., 1.),
+            opacity: 1.,
+        }
+    }
+}
+
+/// A vector shape of lines, Bézier curves and arcs, filled and stroked.
+///
+/// The commands are given in the coordinates of the view box with y pointing down, as in SVG,
+/// and scaled to fit the bounds of the component without distorting them. Paths are tessellated
+/// into triangles and drawn without anti-aliasing.
+#[derive(Default)]
+pub struct Path {
+    display_config: DisplayConfig,
+    commands: Vec<Command>,
+    /// left, top, width and height, the bounding box of the path if `None`
+    view_box: Option<(f32, f32, f32, f32)>,
+    stroke: Stroke,
+    /// triangles for the bounds they were last laid out in
+    geometry: RefCell<Option<Geometry>>,
+    render_pipeline: Option<wgpu::RenderPipeline>,
+    vertex_buffer: Option<wgpu::Buffer>,
+    index_buffer: Option<wgpu::Buffer>,
+    bind_group_layout: Option<wgpu::BindGroupLayout>,
+    bind_group: Option<wgpu::BindGroup>,
+    fill_bind_group: Option<wgpu::BindGroup>,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    world: Affine,
+    clips: Vec<Clip>,
+    depth: i32,
+    id: isize,
+}
+
+impl Component for Path {
+    fn init(
+        &mut self,
+        device: &wgpu::Device,
+        _queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let bind_group_layout = create_bind_group_layout(device);
+        let render_pipeline = common::create_render_pipeline(
+            device,
+            config,
+            &[&bind_group_layout, &bind_group_layout],
+            include_str!("path.wgsl"),
+            &[PathVertex::desc()],
+        );
+        self.render_pipeline = Some(render_pipeline);
+        self.bind_group_layout = Some(bind_group_layout);
+    }
+
+    fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        self.update_geometry(config);
+        let (vertices, indices) = self.create_vertices(config);
+        if indices.is_empty() {
+            return;
+        }
+        let resolution = [config.width as f32, config.height as f32];
+        let buffer = create_rect_buffer(
+            device,
+            RectUniform::new(
+                self.display_config.position,
+                self.display_config.size,
+                [0.; 4],
+                self.display_config.opacity,
+                resolution,
+            )
+            .with_clips(&self.clips)
+            .with_transform(self.world),
+        );
+        let layout = self.bind_group_layout.as_ref().unwrap();
+        let fill_buffer = create_fill_buffer(device, &self.display_config.fill);
+        self.bind_group = Some(create_bind_group(device, layout, &buffer));
+        self.fill_bind_group = Some(create_bind_group(device, layout, &fill_buffer));
+        self.vertex_buffer = Some(common::create_vertex_buffer(device, &vertices));
+        self.index_buffer = Some(common::create_index_buffer(device, &indices));
+
+        render_pass.set_pipeline(self.render_pipeline.as_ref().unwrap());
+        render_pass.set_bind_group(0, self.bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_bind_group(1, self.fill_bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.as_ref().unwrap().slice(..));
+        render_pass.set_index_buffer(
+            self.index_buffer.as_ref().unwrap().slice(..),
+            wgpu::IndexFormat::Uint32,
+        );
+        render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.display_config.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.display_config.size = size;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        self.display_config.position
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.display_config.position = position;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn contains(&self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        self.update_geometry(config);
+        self.geometry
+            .borrow()
+            .as_ref()
+            .is_some_and(|geometry| geometry.contains(shape::pixels(cursor, config)))
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, _config: &wgpu::SurfaceConfiguration) {
+        self.world = transform;
+    }
+
+    fn get_color(&self) -> Option<(f32, f32, f32, f32)> {
+        self.display_config.fill.color()
+    }
+
+    fn set_color(&mut self, color: (f32, f32, f32, f32)) {
+        self.display_config.fill = Fill::Solid(color);
+    }
+
+    fn get_opacity(&self) -> Option<f32> {
+        Some(self.display_config.opacity)
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.display_config.opacity = opacity;
+    }
+}
+
+impl Path {
+    /// left, top, width and height in physical pixels
+    fn bounds(&self, config: &wgpu::SurfaceConfiguration) -> (f32, f32, f32, f32) {
+        let (x, y) = shape::pixels(self.display_config.position, config);
+        let (width, height) = self.display_config.size;
+        (
+            x,
+            y,
+            width / 2. * config.width as f32,
+            height / 2. * config.height as f32,
+        )
+    }
+
+    /// tessellates the path again if it moved, was resized or its fill appeared or vanished
+    fn update_geometry(&self, config: &wgpu::SurfaceConfiguration) {
+        let bounds = self.bounds(config);
+        let filled = self.display_config.fill.visible();
+        let mut geometry = self.geometry.borrow_mut();
+        if geometry
+            .as_ref()
+            .is_some_and(|geometry| geometry.bounds == bounds && geometry.filled == filled)
+        {
+            return;
+        }
+        *geometry = Some(Geometry::new(
+            &self.commands,
+            self.view_box,
+            bounds,
+            filled.then_some(self.display_config.fill_rule),
+            &self.stroke,
+        ));
+    }
+
+    /// the triangles of the path where they are drawn once transformed
+    fn create_vertices(&self, config: &wgpu::SurfaceConfiguration) -> (Vec<PathVertex>, Vec<u32>) {
+        let geometry = self.geometry.borrow();
+        let Some(geometry) = geometry.as_ref() else {
+            return (vec![], vec![]);
+        };
+        let (width, height) = (config.width as f32, config.height as f32);
+        let (r, g, b, a) = self.display_config.stroke_color;
+        let vertices = geometry
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let (x, y) = self.world.apply((vertex.position[0], vertex.position[1]));
+                PathVertex {
+                    position: [x / width * 2. - 1., 1. - y / height * 2.],
+                    color: [r, g, b, a],
+                    filled: !vertex.stroke as u32,
+                }
+            })
+            .collect();
+        (vertices, geometry.indices.clone())
+    }
+}
+
+impl IntoComponent for Path {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::Path(self)
+    }
+}
@@ -1,4 +1,4 @@
-use super::Rect;
+use super::{BorderAlign, Fill, Overflow, Rect};
 
 impl Rect {
     pub fn new() -> Self {
@@ -15,7 +15,13 @@ impl Rect {
     }
 
     pub fn color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
-        self.display_config.color = (r, g, b, a);
+        self.display_config.fill = Fill::Solid((r, g, b, a));
+        self
+    }
+
+    /// a solid color or a gradient, replacing [`Rect::color`]
+    pub fn fill(mut self, fill: Fill) -> Self {
+        self.display_config.fill = fill;
         self
     }
 
@@ -24,8 +30,55 @@ impl Rect {
         self
     }
 
+    /// rounds all four corners
     pub fn radius(mut self, radius: f32) -> Self {
-        self.display_config.radius = radius;
+        self.display_config.radii = [radius; 4];
+        self
+    }
+
+    pub fn corner_radii(
+        mut self,
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        self.display_config.radii = [top_left, top_right, bottom_right, bottom_left];
+        self
+    }
+
+    /// border width in physical pixels
+    pub fn border(mut self, width: f32) -> Self {
+        self.display_config.border_width = width;
+        self
+    }
+
+    pub fn border_color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.display_config.border_color = (r, g, b, a);
+        self
+    }
+
+    /// whether the border is drawn inside the bounds, across the edge or around them
+    pub fn border_align(mut self, align: BorderAlign) -> Self {
+        self.display_config.border_align = align;
+        self
+    }
+
+    /// dashes the border with `dash` long pieces `gap` apart, in physical pixels along the edge
+    pub fn border_dashes(mut self, dash: f32, gap: f32) -> Self {
+        self.display_config.border_dashes = (dash, gap);
+        self
+    }
+
+    /// from 0 for fully transparent to 1, applies to this component but not its children
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.display_config.opacity = opacity;
+        self
+    }
+
+    /// with [`Overflow::Clip`] children are cut off at the bounds and rounded corners
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
         self
     }
 }
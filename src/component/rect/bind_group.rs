@@ -1,13 +1,92 @@
 use wgpu::util::DeviceExt;
 
+use crate::component::{
+    common::{BorderAlign, Clip, MAX_CLIPS},
+    Affine,
+};
+
 #[repr(C)]
 #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
 pub struct RectUniform {
     pub position: [f32; 2],
     pub size: [f32; 2],
-    pub radius: f32,
-    pub _padding: f32,
+    /// top left, top right, bottom right and bottom left
+    pub radii: [f32; 4],
+    pub opacity: f32,
+    /// border width and dash pattern are in the units of the corner radii
+    pub border_width: f32,
     pub resolution: [f32; 2],
+    pub border_color: [f32; 4],
+    /// length of each dash and of the gaps between them, solid if either is zero
+    pub border_dashes: [f32; 2],
+    pub border_align: u32,
+    pub clip_count: u32,
+    /// position and size of each rounded ancestor clipping the component
+    pub clips: [[f32; 4]; MAX_CLIPS],
+    pub clip_radii: [[f32; 4]; MAX_CLIPS],
+    /// maps physical pixels on screen back to the untransformed component
+    pub inverse: [[f32; 4]; 2],
+    pub clip_inverses: [[f32; 4]; MAX_CLIPS * 2],
+}
+
+impl RectUniform {
+    pub fn new(
+        position: (f32, f32),
+        size: (f32, f32),
+        radii: [f32; 4],
+        opacity: f32,
+        resolution: [f32; 2],
+    ) -> Self {
+        Self {
+            position: [position.0, position.1],
+            size: [size.0, size.1],
+            radii,
+            opacity,
+            border_width: 0.,
+            resolution,
+            border_color: [0.; 4],
+            border_dashes: [0.; 2],
+            border_align: BorderAlign::Inner as u32,
+            clip_count: 0,
+            clips: [[0.; 4]; MAX_CLIPS],
+            clip_radii: [[0.; 4]; MAX_CLIPS],
+            inverse: Affine::IDENTITY.rows(),
+            clip_inverses: [[0.; 4]; MAX_CLIPS * 2],
+        }
+    }
+
+    pub fn with_clips(mut self, clips: &[Clip]) -> Self {
+        self.clip_count = clips.len().min(MAX_CLIPS) as u32;
+        for (i, clip) in clips.iter().take(MAX_CLIPS).enumerate() {
+            self.clips[i] = [clip.position.0, clip.position.1, clip.size.0, clip.size.1];
+            self.clip_radii[i] = clip.radii;
+            [self.clip_inverses[i * 2], self.clip_inverses[i * 2 + 1]] = clip.inverse.rows();
+        }
+        self
+    }
+
+    /// `transform` is where the component is drawn, the shader needs its inverse
+    pub fn with_transform(mut self, transform: Affine) -> Self {
+        self.inverse = transform.inverse().unwrap_or_default().rows();
+        self
+    }
+
+    /// a border `width` physical pixels wide, dashed with `dashes` in physical pixels
+    pub fn with_border(
+        mut self,
+        width: f32,
+        color: (f32, f32, f32, f32),
+        align: BorderAlign,
+        dashes: (f32, f32),
+    ) -> Self {
+        // the shaders measure in fractions of half the window height
+        let unit = 2. / self.resolution[1];
+        self.border_width = width * unit;
+        self.border_color = [color.0, color.1, color.2, color.3];
+        self.border_align = align as u32;
+        self.border_dashes = [dashes.0 * unit, dashes.1 * unit];
+        self
+    }
 }
 
 pub fn create_rect_buffer(device: &wgpu::Device, rect: RectUniform) -> wgpu::Buffer {
@@ -7,14 +7,25 @@ use crate::vertex::Vertex;
 
 use self::{bind_group::create_bind_group_layout, vertex::RectVertex};
 
-use super::{common, container::Container, Component, Components, IntoComponent};
+use super::{
+    common, container::Container, Affine, BorderAlign, Clip, Component, Components, Fill, Handlers,
+    IntoComponent, Overflow, Transform, Transitions,
+};
 pub(crate) use bind_group::RectUniform;
 
 struct DisplayConfig {
     size: (f32, f32),
     position: (f32, f32),
-    color: (f32, f32, f32, f32),
-    radius: f32,
+    fill: Fill,
+    /// top left, top right, bottom right and bottom left
+    radii: [f32; 4],
+    opacity: f32,
+    /// in physical pixels
+    border_width: f32,
+    border_color: (f32, f32, f32, f32),
+    border_align: BorderAlign,
+    /// dash and gap lengths in physical pixels, solid if either is zero
+    border_dashes: (f32, f32),
 }
 
 impl Default for DisplayConfig {
@@ -22,8 +33,13 @@ impl Default for DisplayConfig {
         Self {
             size: (2., 2.),
             position: (-1., 1.),
-            color: (0., 0., 1., 1.),
-            radius: 0.,
+            fill: Fill::solid(0., 0., 1., 1.),
+            radii: [0.; 4],
+            opacity: 1.,
+            border_width: 0.,
+            border_color: (0., 0., 0., 1.),
+            border_align: BorderAlign::default(),
+            border_dashes: (0., 0.),
         }
     }
 }
@@ -37,7 +53,17 @@ pub struct Rect {
     indices_length: Option<u32>,
     bind_group_layout: Option<wgpu::BindGroupLayout>,
     bind_group: Option<wgpu::BindGroup>,
+    fill_bind_group: Option<wgpu::BindGroup>,
     children: Components,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    world: Affine,
+    overflow: Overflow,
+    clips: Vec<Clip>,
     depth: i32,
     id: isize,
 }
@@ -49,13 +75,13 @@ impl Component for Rect {
         _queue: &wgpu::Queue,
         config: &wgpu::SurfaceConfiguration,
     ) {
-        let indices = [0, 1, 2, 2, 3, 0];
+        let indices: [u16; 6] = [0, 1, 2, 2, 3, 0];
         let index_buffer = common::create_index_buffer(device, &indices);
         let bind_group_layout = create_bind_group_layout(device);
         let render_pipeline = common::create_render_pipeline(
             device,
             config,
-            &[&bind_group_layout],
+            &[&bind_group_layout, &bind_group_layout],
             include_str!("rect.wgsl"),
             &[RectVertex::desc()],
         );
@@ -73,16 +99,19 @@ impl Component for Rect {
     ) where
         'a: 'b,
     {
-        let bind_group =
-            self.create_bind_group(device, self.bind_group_layout.as_ref().unwrap(), config);
+        let layout = self.bind_group_layout.as_ref().unwrap();
+        let bind_group = self.create_bind_group(device, layout, config);
+        let fill_bind_group = self.create_fill_bind_group(device, layout);
         self.bind_group = Some(bind_group);
+        self.fill_bind_group = Some(fill_bind_group);
 
-        let vertices = self.create_vertices();
+        let vertices = self.create_vertices(config);
         let vertex_buffer = common::create_vertex_buffer(device, &vertices);
         self.vertex_buffer = Some(vertex_buffer);
 
         render_pass.set_pipeline(self.render_pipeline.as_ref().unwrap());
         render_pass.set_bind_group(0, self.bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_bind_group(1, self.fill_bind_group.as_ref().unwrap(), &[]);
         render_pass.set_vertex_buffer(0, self.vertex_buffer.as_ref().unwrap().slice(..));
         render_pass.set_index_buffer(
             self.index_buffer.as_ref().unwrap().slice(..),
@@ -126,6 +155,112 @@ impl Component for Rect {
     fn set_id(&mut self, id: isize) {
         self.id = id;
     }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn contains(&self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        common::rounded_rect_sdf(
+            self.display_config.position,
+            self.display_config.size,
+            self.display_config.radii,
+            cursor,
+            config,
+        ) <= self.border_outset() * 2. / config.height as f32
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn clips_children(&self) -> bool {
+        self.overflow == Overflow::Clip
+    }
+
+    fn corner_radii(&self) -> [f32; 4] {
+        self.display_config.radii
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, _config: &wgpu::SurfaceConfiguration) {
+        self.world = transform;
+    }
+
+    fn get_color(&self) -> Option<(f32, f32, f32, f32)> {
+        self.display_config.fill.color()
+    }
+
+    fn set_color(&mut self, color: (f32, f32, f32, f32)) {
+        self.display_config.fill = Fill::Solid(color);
+    }
+
+    fn set_corner_radii(&mut self, radii: [f32; 4]) {
+        self.display_config.radii = radii;
+    }
+
+    fn get_opacity(&self) -> Option<f32> {
+        Some(self.display_config.opacity)
+    }
+
+    fn set_opacity(&mut self, opacity: f32) {
+        self.display_config.opacity = opacity;
+    }
+}
+
+impl Rect {
+    /// how far in physical pixels the border reaches past the bounds
+    fn border_outset(&self) -> f32 {
+        let DisplayConfig {
+            border_width,
+            border_align,
+            ..
+        } = self.display_config;
+        border_width * border_align as u32 as f32 / 2.
+    }
 }
 
 impl Container for Rect {}
@@ -1,3 +1,5 @@
+use crate::component::create_fill_buffer;
+
 use super::{
     bind_group::{create_bind_group, create_rect_buffer, RectUniform},
     vertex::RectVertex,
@@ -5,51 +7,75 @@ use super::{
 };
 
 impl Rect {
-    pub(crate) fn create_vertices(&self) -> Vec<RectVertex> {
+    pub(crate) fn create_vertices(&self, config: &wgpu::SurfaceConfiguration) -> Vec<RectVertex> {
+        // grown to make room for a border drawn outside the bounds
+        let outset = self.border_outset();
+        let (dx, dy) = (
+            outset * 2. / config.width as f32,
+            outset * 2. / config.height as f32,
+        );
         let (x, y) = self.display_config.position;
+        let (x, y) = (x - dx, y + dy);
         let (width, height) = self.display_config.size;
-        let (r, g, b, a) = self.display_config.color;
+        let (width, height) = (width + dx * 2., height + dy * 2.);
         let vertices = &[
             RectVertex {
-                position: [x, y - height],
-                color: [r, g, b, a],
+                position: self.corner((x, y - height), config),
             }, // A
             RectVertex {
-                position: [x + width, y - height],
-                color: [r, g, b, a],
+                position: self.corner((x + width, y - height), config),
             }, // B
             RectVertex {
-                position: [x + width, y],
-                color: [r, g, b, a],
+                position: self.corner((x + width, y), config),
             }, // C
             RectVertex {
-                position: [x, y],
-                color: [r, g, b, a],
+                position: self.corner((x, y), config),
             }, // D
         ];
         vertices.into()
     }
 
+    /// where the corner at `point` is drawn once transformed
+    fn corner(&self, point: (f32, f32), config: &wgpu::SurfaceConfiguration) -> [f32; 2] {
+        let (x, y) = self.world.apply_ndc(point, config);
+        [x, y]
+    }
+
     pub(crate) fn create_bind_group(
         &self,
         device: &wgpu::Device,
         layout: &wgpu::BindGroupLayout,
         config: &wgpu::SurfaceConfiguration,
     ) -> wgpu::BindGroup {
-        let (x, y) = self.display_config.position;
-        let (width, height) = self.display_config.size;
-        let radius = self.display_config.radius;
         let resolution = [config.width as f32, config.height as f32];
         let buffer = create_rect_buffer(
             device,
-            RectUniform {
-                position: [x, y],
-                size: [width, height],
-                radius,
-                _padding: 0.,
+            RectUniform::new(
+                self.display_config.position,
+                self.display_config.size,
+                self.display_config.radii,
+                self.display_config.opacity,
                 resolution,
-            },
+            )
+            .with_border(
+                self.display_config.border_width,
+                self.display_config.border_color,
+                self.display_config.border_align,
+                self.display_config.border_dashes,
+            )
+            .with_clips(&self.clips)
+            .with_transform(self.world),
         );
         create_bind_group(device, layout, &buffer)
     }
+
+    /// the fill in a bind group of its own, read by `fillColor` in the shader
+    pub(crate) fn create_fill_bind_group(
+        &self,
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+    ) -> wgpu::BindGroup {
+        let buffer = create_fill_buffer(device, &self.display_config.fill);
+        create_bind_group(device, layout, &buffer)
+    }
 }
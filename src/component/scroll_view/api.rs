@@ -0,0 +1,34 @@
+use super::ScrollView;
+
+impl ScrollView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width * 2., height * 2.);
+        self
+    }
+
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x * 2. - 1., -(y * 2. - 1.));
+        self
+    }
+
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// allow scrolling sideways, off by default
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// allow scrolling up and down, on by default
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+}
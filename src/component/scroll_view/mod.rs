@@ -0,0 +1,348 @@
+mod api;
+
+use super::{
+    container::Container, Affine, Component, Components, Handlers, IntoComponent, Rect, Transform,
+    Transitions,
+};
+
+/// thickness of the scrollbars in physical pixels
+const SCROLLBAR_WIDTH: f32 = 8.;
+/// gap between the scrollbars and the edges of the view in physical pixels
+const SCROLLBAR_INSET: f32 = 2.;
+/// shortest a scrollbar thumb gets in physical pixels
+const MIN_THUMB_LENGTH: f32 = 20.;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    fn index(self) -> usize {
+        match self {
+            Axis::Horizontal => 0,
+            Axis::Vertical => 1,
+        }
+    }
+}
+
+/// A container that clips its children to its bounds and scrolls them with the mouse wheel or
+/// by dragging its scrollbars.
+pub struct ScrollView {
+    position: (f32, f32),
+    size: (f32, f32),
+    children: Components,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+    depth: i32,
+    id: isize,
+    horizontal: bool,
+    vertical: bool,
+    /// how far the content is scrolled in physical pixels
+    offset: (f32, f32),
+    /// size of the content in physical pixels, at least the size of the view
+    content_size: (f32, f32),
+    thumbs: [Rect; 2],
+}
+
+impl Default for ScrollView {
+    fn default() -> Self {
+        Self {
+            position: (-1., 1.),
+            size: (2., 2.),
+            children: vec![],
+            handlers: Handlers::default(),
+            transitions: Transitions::default(),
+            hidden: false,
+            focusable: false,
+            draggable: false,
+            transform: Transform::default(),
+            depth: 0,
+            id: 0,
+            horizontal: false,
+            vertical: true,
+            offset: (0., 0.),
+            content_size: (0., 0.),
+            thumbs: [
+                Rect::new().color(1., 1., 1., 0.4).radius(0.01),
+                Rect::new().color(1., 1., 1., 0.4).radius(0.01),
+            ],
+        }
+    }
+}
+
+impl Component for ScrollView {
+    fn init(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.thumbs
+            .iter_mut()
+            .for_each(|thumb| thumb.init(device, queue, config));
+    }
+
+    fn prepare(
+        &mut self,
+        _device: &wgpu::Device,
+        _queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let (width, height) = (config.width as f32, config.height as f32);
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            if let Some((x, y, w, h)) = self.thumb_rect(axis, config) {
+                let thumb = &mut self.thumbs[axis.index()];
+                thumb.set_position((x / width * 2. - 1., 1. - y / height * 2.));
+                thumb.set_size((w / width * 2., h / height * 2.));
+            }
+        }
+    }
+
+    fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        let shown = [Axis::Horizontal, Axis::Vertical].map(|axis| self.scrollable(axis, config));
+        for (thumb, shown) in self.thumbs.iter_mut().zip(shown) {
+            if shown {
+                thumb.render(device, config, render_pass);
+            }
+        }
+    }
+
+    fn draws_over_children(&self) -> bool {
+        true
+    }
+
+    fn children(&mut self) -> Option<&mut Components> {
+        Some(&mut self.children)
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.size = size;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        self.position
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.position = position;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, config: &wgpu::SurfaceConfiguration) {
+        self.thumbs
+            .iter_mut()
+            .for_each(|thumb| thumb.set_world_transform(transform, config));
+    }
+
+    fn clips_children(&self) -> bool {
+        true
+    }
+}
+
+impl ScrollView {
+    fn pixel_size(&self, config: &wgpu::SurfaceConfiguration) -> (f32, f32) {
+        (
+            self.size.0 / 2. * config.width as f32,
+            self.size.1 / 2. * config.height as f32,
+        )
+    }
+
+    pub(crate) fn offset(&self) -> (f32, f32) {
+        self.offset
+    }
+
+    pub(crate) fn set_offset(&mut self, offset: (f32, f32)) {
+        self.offset = offset;
+    }
+
+    pub(crate) fn set_content_size(&mut self, content_size: (f32, f32)) {
+        self.content_size = content_size;
+    }
+
+    /// the furthest the content can be scrolled along each axis
+    pub(crate) fn max_offset(&self, config: &wgpu::SurfaceConfiguration) -> (f32, f32) {
+        let size = self.pixel_size(config);
+        (
+            match self.horizontal {
+                true => (self.content_size.0 - size.0).max(0.),
+                false => 0.,
+            },
+            match self.vertical {
+                true => (self.content_size.1 - size.1).max(0.),
+                false => 0.,
+            },
+        )
+    }
+
+    pub(crate) fn clamp(
+        &self,
+        offset: (f32, f32),
+        config: &wgpu::SurfaceConfiguration,
+    ) -> (f32, f32) {
+        let max = self.max_offset(config);
+        (offset.0.clamp(0., max.0), offset.1.clamp(0., max.1))
+    }
+
+    fn scrollable(&self, axis: Axis, config: &wgpu::SurfaceConfiguration) -> bool {
+        let max = self.max_offset(config);
+        match axis {
+            Axis::Horizontal => max.0 > 0.,
+            Axis::Vertical => max.1 > 0.,
+        }
+    }
+
+    /// left, top, width and height of the scrollbar thumb in physical pixels, relative to the
+    /// window
+    fn thumb_rect(
+        &self,
+        axis: Axis,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Option<(f32, f32, f32, f32)> {
+        if !self.scrollable(axis, config) {
+            return None;
+        }
+        let left = (self.position.0 + 1.) / 2. * config.width as f32;
+        let top = (1. - self.position.1) / 2. * config.height as f32;
+        let size = self.pixel_size(config);
+        let max = self.max_offset(config);
+        let track = |length: f32| length - 2. * SCROLLBAR_INSET - SCROLLBAR_WIDTH;
+        let rect = match axis {
+            Axis::Horizontal => {
+                let track = track(size.0);
+                let thumb = (track * size.0 / self.content_size.0).max(MIN_THUMB_LENGTH);
+                (
+                    left + SCROLLBAR_INSET + self.offset.0 / max.0 * (track - thumb),
+                    top + size.1 - SCROLLBAR_INSET - SCROLLBAR_WIDTH,
+                    thumb,
+                    SCROLLBAR_WIDTH,
+                )
+            }
+            Axis::Vertical => {
+                let track = track(size.1);
+                let thumb = (track * size.1 / self.content_size.1).max(MIN_THUMB_LENGTH);
+                (
+                    left + size.0 - SCROLLBAR_INSET - SCROLLBAR_WIDTH,
+                    top + SCROLLBAR_INSET + self.offset.1 / max.1 * (track - thumb),
+                    SCROLLBAR_WIDTH,
+                    thumb,
+                )
+            }
+        };
+        Some(rect)
+    }
+
+    /// the scrollbar whose thumb is under `cursor`, in physical pixels
+    pub(crate) fn thumb_at(
+        &self,
+        cursor: (f32, f32),
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Option<Axis> {
+        [Axis::Horizontal, Axis::Vertical].into_iter().find(|axis| {
+            self.thumb_rect(*axis, config).is_some_and(|(x, y, w, h)| {
+                cursor.0 >= x && cursor.0 < x + w && cursor.1 >= y && cursor.1 < y + h
+            })
+        })
+    }
+
+    /// how far the content scrolls when the thumb of `axis` moves by one pixel
+    pub(crate) fn thumb_ratio(&self, axis: Axis, config: &wgpu::SurfaceConfiguration) -> f32 {
+        let Some((_, _, w, h)) = self.thumb_rect(axis, config) else {
+            return 0.;
+        };
+        let size = self.pixel_size(config);
+        let max = self.max_offset(config);
+        let track = |length: f32| length - 2. * SCROLLBAR_INSET - SCROLLBAR_WIDTH;
+        match axis {
+            Axis::Horizontal => max.0 / (track(size.0) - w).max(1.),
+            Axis::Vertical => max.1 / (track(size.1) - h).max(1.),
+        }
+    }
+}
+
+impl Container for ScrollView {}
+
+impl IntoComponent for ScrollView {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::ScrollView(self)
+    }
+}
@@ -0,0 +1,85 @@
+use wgpu::util::DeviceExt;
+
+use super::{LineCap, ShapeKind};
+
+#[repr(C)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+pub struct ShapeUniform {
+    pub stroke: [f32; 4],
+    /// end points of a line in screen space
+    pub start: [f32; 2],
+    pub end: [f32; 2],
+    /// in physical pixels, centered on the outline
+    pub stroke_width: f32,
+    /// width of a line in physical pixels
+    pub thickness: f32,
+    pub kind: u32,
+    pub cap: u32,
+}
+
+impl ShapeUniform {
+    pub fn new(kind: ShapeKind, stroke_width: f32, stroke: (f32, f32, f32, f32)) -> Self {
+        Self {
+            stroke: [stroke.0, stroke.1, stroke.2, stroke.3],
+            start: [0., 0.],
+            end: [0., 0.],
+            stroke_width,
+            thickness: 0.,
+            kind: kind as u32,
+            cap: 0,
+        }
+    }
+
+    pub fn with_line(
+        mut self,
+        from: (f32, f32),
+        to: (f32, f32),
+        thickness: f32,
+        cap: LineCap,
+    ) -> Self {
+        self.start = [from.0, from.1];
+        self.end = [to.0, to.1];
+        self.thickness = thickness;
+        self.cap = cap as u32;
+        self
+    }
+}
+
+pub fn create_uniform_buffer(device: &wgpu::Device, contents: &[u8]) -> wgpu::Buffer {
+    device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("shape buffer"),
+        contents,
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+    })
+}
+
+pub fn create_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+        label: Some("shape bind group layout"),
+    })
+}
+
+pub fn create_bind_group(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    buffer: &wgpu::Buffer,
+) -> wgpu::BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: buffer.as_entire_binding(),
+        }],
+        label: Some("shape bind group"),
+    })
+}
@@ -0,0 +1,189 @@
+mod bind_group;
+mod vertex;
+
+use crate::vertex::Vertex;
+
+use self::{
+    bind_group::{create_bind_group, create_bind_group_layout, create_uniform_buffer},
+    vertex::ShapeVertex,
+};
+
+use super::{common, create_fill_buffer, Affine, Fill, RectUniform};
+pub(crate) use bind_group::ShapeUniform;
+
+/// How the ends of a [`crate::component::Line`] are drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineCap {
+    /// ends flat at the end points
+    #[default]
+    Butt = 0,
+    /// half a circle around each end point
+    Round = 1,
+    /// ends flat, half the thickness past the end points
+    Square = 2,
+}
+
+/// which distance function `shape.wgsl` draws
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ShapeKind {
+    #[default]
+    Ellipse = 0,
+    Circle = 1,
+    Line = 2,
+}
+
+/// Pipeline and buffers of a shape drawn from a signed distance function in `shape.wgsl`,
+/// shared by [`crate::component::Ellipse`] and [`crate::component::Line`].
+#[derive(Default)]
+pub(crate) struct ShapeRenderer {
+    render_pipeline: Option<wgpu::RenderPipeline>,
+    vertex_buffer: Option<wgpu::Buffer>,
+    index_buffer: Option<wgpu::Buffer>,
+    bind_group_layout: Option<wgpu::BindGroupLayout>,
+    rect_bind_group: Option<wgpu::BindGroup>,
+    shape_bind_group: Option<wgpu::BindGroup>,
+    fill_bind_group: Option<wgpu::BindGroup>,
+}
+
+/// drawn as two triangles over the quad returned by [`quad`]
+const INDICES: [u16; 6] = [0, 1, 2, 2, 3, 0];
+
+impl ShapeRenderer {
+    pub fn init(&mut self, device: &wgpu::Device, config: &wgpu::SurfaceConfiguration) {
+        let bind_group_layout = create_bind_group_layout(device);
+        let render_pipeline = common::create_render_pipeline(
+            device,
+            config,
+            &[&bind_group_layout, &bind_group_layout, &bind_group_layout],
+            include_str!("shape.wgsl"),
+            &[ShapeVertex::desc()],
+        );
+        self.index_buffer = Some(common::create_index_buffer(device, &INDICES));
+        self.render_pipeline = Some(render_pipeline);
+        self.bind_group_layout = Some(bind_group_layout);
+    }
+
+    /// draws the shape over `corners`, with the bounds, opacity, clips and transform in `rect`
+    /// and the inside painted with `fill`
+    pub fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        render_pass: &mut wgpu::RenderPass<'b>,
+        rect: RectUniform,
+        shape: ShapeUniform,
+        fill: &Fill,
+        corners: [[f32; 2]; 4],
+    ) where
+        'a: 'b,
+    {
+        let layout = self.bind_group_layout.as_ref().unwrap();
+        let rect_buffer = create_uniform_buffer(device, bytemuck::cast_slice(&[rect]));
+        let shape_buffer = create_uniform_buffer(device, bytemuck::cast_slice(&[shape]));
+        self.rect_bind_group = Some(create_bind_group(device, layout, &rect_buffer));
+        let fill_buffer = create_fill_buffer(device, fill);
+        self.shape_bind_group = Some(create_bind_group(device, layout, &shape_buffer));
+        self.fill_bind_group = Some(create_bind_group(device, layout, &fill_buffer));
+
+        let vertices = corners.map(|position| ShapeVertex { position });
+        self.vertex_buffer = Some(common::create_vertex_buffer(device, &vertices));
+
+        render_pass.set_pipeline(self.render_pipeline.as_ref().unwrap());
+        render_pass.set_bind_group(0, self.rect_bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_bind_group(1, self.shape_bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_bind_group(2, self.fill_bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.as_ref().unwrap().slice(..));
+        render_pass.set_index_buffer(
+            self.index_buffer.as_ref().unwrap().slice(..),
+            wgpu::IndexFormat::Uint16,
+        );
+        render_pass.draw_indexed(0..INDICES.len() as u32, 0, 0..1);
+    }
+}
+
+/// the corners of the bounds grown by `margin` physical pixels on every side, where they are
+/// drawn once transformed, in counter clockwise order from the bottom left
+pub(crate) fn quad(
+    position: (f32, f32),
+    size: (f32, f32),
+    margin: f32,
+    world: Affine,
+    config: &wgpu::SurfaceConfiguration,
+) -> [[f32; 2]; 4] {
+    let margin = (
+        margin / config.width as f32 * 2.,
+        margin / config.height as f32 * 2.,
+    );
+    let (left, top) = (position.0 - margin.0, position.1 + margin.1);
+    let (right, bottom) = (
+        position.0 + size.0 + margin.0,
+        position.1 - size.1 - margin.1,
+    );
+    [(left, bottom), (right, bottom), (right, top), (left, top)].map(|corner| {
+        let (x, y) = world.apply_ndc(corner, config);
+        [x, y]
+    })
+}
+
+/// the screen space `point` in physical pixels from the top left corner of the window
+pub(crate) fn pixels(point: (f32, f32), config: &wgpu::SurfaceConfiguration) -> (f32, f32) {
+    (
+        (point.0 + 1.) / 2. * config.width as f32,
+        (1. - point.1) / 2. * config.height as f32,
+    )
+}
+
+/// center and radii in physical pixels of the ellipse filling the bounds
+pub(crate) fn ellipse_geometry(
+    position: (f32, f32),
+    size: (f32, f32),
+    config: &wgpu::SurfaceConfiguration,
+) -> ((f32, f32), (f32, f32)) {
+    let center = pixels((position.0 + size.0 / 2., position.1 - size.1 / 2.), config);
+    let radii = (
+        size.0 / 4. * config.width as f32,
+        size.1 / 4. * config.height as f32,
+    );
+    (center, radii)
+}
+
+/// Approximate signed distance from `p` to an ellipse with radii `r` around the origin, exact
+/// for circles, the same function as `ellipseSDF` in `shape.wgsl`.
+pub(crate) fn ellipse_sdf(p: (f32, f32), r: (f32, f32)) -> f32 {
+    let k0 = ((p.0 / r.0).powi(2) + (p.1 / r.1).powi(2)).sqrt();
+    let k1 = ((p.0 / (r.0 * r.0)).powi(2) + (p.1 / (r.1 * r.1)).powi(2)).sqrt();
+    if k1 == 0. {
+        return -r.0.min(r.1);
+    }
+    k0 * (k0 - 1.) / k1
+}
+
+/// Signed distance from `p` to the segment from `a` to `b` drawn `thickness` wide, the same
+/// function as `segmentSDF` in `shape.wgsl`.
+pub(crate) fn segment_sdf(
+    p: (f32, f32),
+    a: (f32, f32),
+    b: (f32, f32),
+    thickness: f32,
+    cap: LineCap,
+) -> f32 {
+    let length = (b.0 - a.0).hypot(b.1 - a.1);
+    let dir = match length > 0. {
+        true => ((b.0 - a.0) / length, (b.1 - a.1) / length),
+        false => (1., 0.),
+    };
+    let d = (p.0 - a.0, p.1 - a.1);
+    let local = (d.0 * dir.0 + d.1 * dir.1, d.1 * dir.0 - d.0 * dir.1);
+    let extent = match cap {
+        LineCap::Round => {
+            let h = local.0.clamp(0., length);
+            return (local.0 - h).hypot(local.1) - thickness / 2.;
+        }
+        LineCap::Butt => length / 2.,
+        LineCap::Square => length / 2. + thickness / 2.,
+    };
+    common::rect_sdf(
+        (local.0 - length / 2., local.1),
+        (extent, thickness / 2.),
+        0.,
+    )
+}
@@ -0,0 +1,210 @@
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+};
+
+
+struct RectUniform {
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radii: vec4<f32>,
+    opacity: f32,
+    border_width: f32,
+    resolution: vec2<f32>,
+    border_color: vec4<f32>,
+    border_dashes: vec2<f32>,
+    border_align: u32,
+    clip_count: u32,
+    clips: array<vec4<f32>, 4>,
+    clip_radii: array<vec4<f32>, 4>,
+    inverse: array<vec4<f32>, 2>,
+    clip_inverses: array<vec4<f32>, 8>,
+}
+
+struct ShapeUniform {
+    stroke: vec4<f32>,
+    start: vec2<f32>,
+    end: vec2<f32>,
+    stroke_width: f32,
+    thickness: f32,
+    kind: u32,
+    cap: u32,
+}
+
+struct FillUniform {
+    center: vec2<f32>,
+    angle: f32,
+    kind: u32,
+    stop_count: u32,
+    offsets: array<vec4<f32>, 2>,
+    colors: array<vec4<f32>, 8>,
+}
+
+@group(0) @binding(0)
+var<uniform> rect: RectUniform;
+
+@group(1) @binding(0)
+var<uniform> shape: ShapeUniform;
+
+@group(2) @binding(0)
+var<uniform> fill: FillUniform;
+
+@vertex
+fn vs_main(
+    model: VertexInput,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(model.position, 0.0, 1.0);
+    return out;
+}
+
+fn rectSDF(p: vec2f, b: vec2f, r: f32) -> f32 {
+    let d = abs(p) - b + r;
+    return min(max(d.x, d.y), 0.0) + length(vec2(max(d.x, 0.0), max(d.y, 0.0))) - r;
+}
+
+// the radius of the corner nearest to `p`, `radii` go clockwise from the top left with y down
+fn cornerRadius(p: vec2f, radii: vec4f) -> f32 {
+    let side = select(radii.wz, radii.xy, p.y < 0.0);
+    return select(side.x, side.y, p.x > 0.0);
+}
+
+// approximate distance to an ellipse with radii `r`, exact for circles
+fn ellipseSDF(p: vec2f, r: vec2f) -> f32 {
+    let k0 = length(p / r);
+    let k1 = length(p / (r * r));
+    if k1 == 0.0 {
+        return -min(r.x, r.y);
+    }
+    return k0 * (k0 - 1.0) / k1;
+}
+
+// distance to the segment from `a` to `b` drawn `thickness` wide with the given cap
+fn segmentSDF(p: vec2f, a: vec2f, b: vec2f, thickness: f32, cap: u32) -> f32 {
+    let length_ab = length(b - a);
+    var dir = vec2(1.0, 0.0);
+    if length_ab > 0.0 {
+        dir = (b - a) / length_ab;
+    }
+    let local = vec2(dot(p - a, dir), dot(p - a, vec2(-dir.y, dir.x)));
+    // round
+    if cap == 1u {
+        let h = clamp(local.x, 0.0, length_ab);
+        return length(vec2(local.x - h, local.y)) - thickness / 2;
+    }
+    var extent = length_ab / 2;
+    // square
+    if cap == 2u {
+        extent += thickness / 2;
+    }
+    return rectSDF(local - vec2(length_ab / 2, 0.0), vec2(extent, thickness / 2), 0.0);
+}
+
+// maps the pixel `p` with the affine matrix given by its two rows
+fn transform(row0: vec4f, row1: vec4f, p: vec2f) -> vec2f {
+    return vec2(dot(row0.xyz, vec3(p, 1.0)), dot(row1.xyz, vec3(p, 1.0)));
+}
+
+// whether the pixel falls outside one of the ancestors clipping the component
+fn clipped(pixel: vec2f, ratio: f32) -> bool {
+    for (var i = 0u; i < rect.clip_count; i++) {
+        let clip = rect.clips[i];
+        let local = transform(rect.clip_inverses[i * 2], rect.clip_inverses[i * 2 + 1], pixel);
+        let uv = local / rect.resolution.xy;
+        let center = vec2(clip.x + clip.z / 2, clip.y - clip.w / 2.0);
+        let coords = (uv * 2 - 1 - vec2(center.x, -center.y)) * vec2(ratio, 1);
+        let half_size = clip.zw / 2 * vec2(ratio, 1);
+        if rectSDF(coords, half_size, cornerRadius(coords, rect.clip_radii[i])) > 0.0 {
+            return true;
+        }
+    }
+    return false;
+}
+
+// screen space to physical pixels
+fn pixels(p: vec2f) -> vec2f {
+    return vec2(p.x + 1, 1 - p.y) / 2 * rect.resolution;
+}
+
+// the color between the stops at `t` along the gradient
+fn stopColor(t: f32) -> vec4f {
+    var color = fill.colors[0];
+    for (var i = 1u; i < fill.stop_count; i++) {
+        let start = fill.offsets[(i - 1u) / 4u][(i - 1u) % 4u];
+        let end = fill.offsets[i / 4u][i % 4u];
+        if t >= end {
+            color = fill.colors[i];
+        } else if t > start {
+            color = mix(fill.colors[i - 1u], fill.colors[i], (t - start) / (end - start));
+            break;
+        } else {
+            break;
+        }
+    }
+    return color;
+}
+
+// the fill at `p` from the center of bounds with the half size `half`, both with y down
+fn fillColor(p: vec2f, half: vec2f) -> vec4f {
+    let center = (fill.center * 2 - 1) * half;
+    var t = 0.0;
+    switch fill.kind {
+        // linear
+        case 1u: {
+            let dir = vec2(cos(fill.angle), sin(fill.angle));
+            let extent = dot(abs(dir), half);
+            if extent > 0.0 {
+                t = (dot(p, dir) / extent + 1) / 2;
+            }
+        }
+        // radial
+        case 2u: {
+            let reach = length(half + abs(center));
+            if reach > 0.0 {
+                t = length(p - center) / reach;
+            }
+        }
+        // conic
+        case 3u: {
+            let d = p - center;
+            t = fract((atan2(d.y, d.x) - fill.angle) / 6.2831855);
+        }
+        default: {}
+    }
+    return stopColor(t);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let pixel = transform(rect.inverse[0], rect.inverse[1], in.position.xy);
+    let ratio = rect.resolution.x / rect.resolution.y;
+    let center = pixels(vec2(rect.position.x + rect.size.x / 2, rect.position.y - rect.size.y / 2.0));
+    let radii = rect.size / 4 * rect.resolution;
+    var distance = 0.0;
+    switch shape.kind {
+        case 0u: {
+            distance = ellipseSDF(pixel - center, radii);
+        }
+        case 1u: {
+            distance = length(pixel - center) - min(radii.x, radii.y);
+        }
+        default: {
+            distance = segmentSDF(pixel, pixels(shape.start), pixels(shape.end), shape.thickness, shape.cap);
+        }
+    }
+    // the stroke is centered on the outline, one pixel of each edge is blended for anti-aliasing
+    let half_stroke = shape.stroke_width / 2;
+    let coverage = clamp(0.5 - (distance - half_stroke), 0.0, 1.0);
+    if coverage <= 0.0 || clipped(in.position.xy, ratio) {
+        discard;
+    }
+    let fill_color = fillColor(pixel - center, radii);
+    var color = fill_color;
+    if half_stroke > 0.0 {
+        color = mix(shape.stroke, fill_color, clamp(0.5 - (distance + half_stroke), 0.0, 1.0));
+    }
+    return vec4(color.rgb, color.a * coverage * rect.opacity);
+}
@@ -1,4 +1,4 @@
-use crate::context::Context;
+use crate::component::{Component, Fill};
 
 use super::Text;
 
@@ -13,12 +13,17 @@ impl Text {
     }
 
     pub fn color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
-        self.config.color = glyphon::Color::rgba(
-            (r * 255.) as u8,
-            (g * 255.) as u8,
-            (b * 255.) as u8,
-            (a * 255.) as u8,
-        );
+        self.set_color((r, g, b, a));
+        self
+    }
+
+    /// a solid color or a gradient over the laid out text, each glyph takes the color at its
+    /// center
+    pub fn fill(mut self, fill: Fill) -> Self {
+        match fill.color() {
+            Some(color) => self.set_color(color),
+            None => self.config.gradient = Some(fill),
+        }
         self
     }
 
@@ -44,8 +49,14 @@ impl Text {
         self
     }
 
-    pub fn on_click(mut self, f: fn(Context)) -> Self {
-        self.on_click = Some(f);
+    /// allow selecting the text with the mouse and copying it to the clipboard
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    pub fn highlight_color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.highlights.set_color((r, g, b, a));
         self
     }
 }
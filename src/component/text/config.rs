@@ -1,13 +1,23 @@
 use glyphon::TextBounds;
 
+use crate::component::{Affine, Fill};
+
 #[derive(Clone, Debug)]
 pub struct TextConfig {
     pub(crate) left: f32,
     pub(crate) top: f32,
+    /// how much the transform of the component enlarges the text, see [`TextConfig::follow`]
     pub(crate) scale: f32,
     pub(crate) color: glyphon::Color,
+    /// paints each glyph where it sits in the laid out text, over `color`
+    pub(crate) gradient: Option<Fill>,
     pub(crate) text_bounds: TextBounds,
     pub(crate) content: String,
+    /// how far the transform of the component moves the text, in physical pixels
+    pub(crate) offset: (f32, f32),
+    /// whether a transform the glyphs cannot follow has been reported, see
+    /// [`TextConfig::follow`]
+    pub(crate) warned: bool,
 }
 
 impl Default for TextConfig {
@@ -17,17 +27,89 @@ impl Default for TextConfig {
             top: 1.,
             scale: 1.,
             color: glyphon::Color::rgba(255, 255, 255, 255),
+            gradient: None,
             text_bounds: TextBounds::default(),
             content: String::new(),
+            offset: (0., 0.),
+            warned: false,
         }
     }
 }
 
 impl TextConfig {
+    /// moves the text to where `transform` takes its top left corner and scales it by the
+    /// average stretch of `transform`
+    ///
+    /// Glyphs are drawn upright and evenly scaled, so a rotation, skew, mirror or uneven scale
+    /// would be lost on them. The first such transform of each text is logged as a warning.
+    pub(crate) fn follow(&mut self, transform: Affine, config: &wgpu::SurfaceConfiguration) {
+        let [a, b, _, d, e, _] = transform.0;
+        self.scale = (a * e - b * d).abs().sqrt();
+        if !self.warned && !upright(transform) {
+            self.warned = true;
+            let start: String = self.content.chars().take(20).collect();
+            log::warn!(
+                "text {start:?} is drawn upright, the rotation, skew, mirror or uneven scale of \
+                 its transform or of an ancestor or camera is not applied to the glyphs"
+            );
+        }
+        let origin = (
+            (self.left + 1.) / 2. * config.width as f32,
+            (-self.top + 1.) / 2. * config.height as f32,
+        );
+        let (x, y) = transform.apply(origin);
+        self.offset = (x - origin.0, y - origin.1);
+    }
+
     pub(crate) fn fit_screen(&self, config: &wgpu::SurfaceConfiguration) -> Self {
         let mut new_instance = self.clone();
-        new_instance.left = (self.left + 1.) / 2. * config.width as f32;
-        new_instance.top = (-self.top + 1.) / 2. * config.height as f32;
+        new_instance.left = (self.left + 1.) / 2. * config.width as f32 + self.offset.0;
+        new_instance.top = (-self.top + 1.) / 2. * config.height as f32 + self.offset.1;
         new_instance
     }
 }
+
+/// whether `transform` only moves the text and scales it evenly, which the glyphs can follow
+fn upright(transform: Affine) -> bool {
+    let [a, b, _, d, e, _] = transform.0;
+    let close = |x: f32, y: f32| (x - y).abs() <= 1e-4 * x.abs().max(y.abs()).max(1.);
+    close(b, 0.) && close(d, 0.) && close(a, e) && a > 0.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::Transform;
+
+    fn warned(transform: Transform) -> bool {
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            width: 200,
+            height: 100,
+            present_mode: wgpu::PresentMode::Fifo,
+            desired_maximum_frame_latency: 2,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+        };
+        let mut text = TextConfig::default();
+        text.follow(transform.matrix((0., 0., 100., 20.)), &config);
+        text.warned
+    }
+
+    #[test]
+    fn moves_and_even_scales_are_followed() {
+        assert!(!warned(Transform::new()));
+        assert!(!warned(Transform::new().translate(30., -10.)));
+        assert!(!warned(Transform::new().scale(2., 2.)));
+    }
+
+    #[test]
+    fn turns_and_warps_are_reported() {
+        assert!(warned(Transform::new().rotate(0.3)));
+        assert!(warned(Transform::new().rotate(std::f32::consts::PI)));
+        assert!(warned(Transform::new().skew(0.2, 0.)));
+        assert!(warned(Transform::new().scale(-1., 1.)));
+        assert!(warned(Transform::new().scale(2., 1.)));
+    }
+}
@@ -0,0 +1,74 @@
+use super::TextConfig;
+use crate::component::{Clip, Component, Rect};
+
+/// Selection background drawn behind glyphs, one rect per selected layout run.
+pub(crate) struct Highlights {
+    rects: Vec<Rect>,
+    count: usize,
+    clips: Vec<Clip>,
+    color: (f32, f32, f32, f32),
+}
+
+impl Default for Highlights {
+    fn default() -> Self {
+        Self {
+            rects: vec![],
+            count: 0,
+            clips: vec![],
+            color: (0.2, 0.4, 0.8, 1.),
+        }
+    }
+}
+
+impl Highlights {
+    pub fn set_color(&mut self, color: (f32, f32, f32, f32)) {
+        self.color = color;
+    }
+
+    pub fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    /// places the rects over `spans` (see [`super::layout::selection_spans`]) of `text`, which is
+    /// already fit to the screen
+    pub fn layout(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        text: &TextConfig,
+        spans: &[(f32, f32, f32)],
+        line_height: f32,
+    ) {
+        let (width, height) = (config.width as f32, config.height as f32);
+        let (origin, scale) = ((text.left, text.top), text.scale);
+        while self.rects.len() < spans.len() {
+            let (r, g, b, a) = self.color;
+            let mut rect = Rect::new().color(r, g, b, a);
+            rect.init(device, queue, config);
+            self.rects.push(rect);
+        }
+        for ((x, y, span), rect) in spans.iter().zip(self.rects.iter_mut()) {
+            rect.set_position((
+                (origin.0 + x * scale) / width * 2. - 1.,
+                1. - (origin.1 + y * scale) / height * 2.,
+            ));
+            rect.set_size((span * scale / width * 2., line_height * scale / height * 2.));
+            rect.set_clips(&self.clips);
+        }
+        self.count = spans.len();
+    }
+
+    pub fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        self.rects[..self.count]
+            .iter_mut()
+            .for_each(|rect| rect.render(device, config, render_pass));
+    }
+}
@@ -0,0 +1,81 @@
+use glyphon::{Buffer, Cursor};
+
+/// orders two cursors by position in the buffer
+pub(crate) fn ordered(a: Cursor, b: Cursor) -> (Cursor, Cursor) {
+    match (a.line, a.index) <= (b.line, b.index) {
+        true => (a, b),
+        false => (b, a),
+    }
+}
+
+/// pixel `(x, line_top)` of the caret at `cursor`, `None` if its line is scrolled out of view
+pub(crate) fn cursor_position(buffer: &Buffer, cursor: Cursor) -> Option<(f32, f32)> {
+    let mut runs = buffer
+        .layout_runs()
+        .filter(|run| run.line_i == cursor.line)
+        .peekable();
+    while let Some(run) = runs.next() {
+        let Some(last) = run.glyphs.last() else {
+            return Some((0., run.line_top));
+        };
+        if cursor.index < last.end || runs.peek().is_none() {
+            let x = run
+                .glyphs
+                .iter()
+                .find(|glyph| glyph.start >= cursor.index)
+                .map(|glyph| glyph.x)
+                .unwrap_or(last.x + last.w);
+            return Some((x, run.line_top));
+        }
+    }
+    None
+}
+
+/// pixel `(x, line_top, width)` of every visible run covered by the selection
+pub(crate) fn selection_spans(buffer: &Buffer, a: Cursor, b: Cursor) -> Vec<(f32, f32, f32)> {
+    let (start, end) = ordered(a, b);
+    let start = (start.line, start.index);
+    let end = (end.line, end.index);
+    buffer
+        .layout_runs()
+        .filter(|run| run.line_i >= start.0 && run.line_i <= end.0)
+        .filter_map(|run| {
+            let span = run
+                .glyphs
+                .iter()
+                .filter(|glyph| {
+                    (run.line_i, glyph.start) >= start && (run.line_i, glyph.end) <= end
+                })
+                .fold(None, |span: Option<(f32, f32)>, glyph| {
+                    let (left, right) = span.unwrap_or((glyph.x, glyph.x + glyph.w));
+                    Some((left.min(glyph.x), right.max(glyph.x + glyph.w)))
+                });
+            match span {
+                Some((left, right)) => Some((left, run.line_top, right - left)),
+                // keep selected blank lines visible
+                None if run.glyphs.is_empty() && run.line_i > start.0 && run.line_i < end.0 => {
+                    Some((0., run.line_top, buffer.metrics().font_size / 4.))
+                }
+                None => None,
+            }
+        })
+        .collect()
+}
+
+/// byte range of the word around `index` in `text`
+pub(crate) fn word_at(text: &str, index: usize) -> (usize, usize) {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let start = text[..index]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map(|(i, _)| i)
+        .unwrap_or(index);
+    let end = text[index..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map(|(i, _)| index + i)
+        .unwrap_or(text.len());
+    (start, end.max(start))
+}
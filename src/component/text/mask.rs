@@ -0,0 +1,125 @@
+use wgpu::util::DeviceExt;
+
+use crate::component::{common, Clip, RectUniform};
+
+/// Masks the glyphs of a text component to the rounded or transformed ancestors clipping it,
+/// which the scissor rect cannot follow. The mask is drawn into the stencil right before the
+/// text, as glyphon draws with its own shader.
+#[derive(Default)]
+pub(crate) struct ClipMask {
+    clips: Vec<Clip>,
+    clear_pipeline: Option<wgpu::RenderPipeline>,
+    mask_pipeline: Option<wgpu::RenderPipeline>,
+    bind_group_layout: Option<wgpu::BindGroupLayout>,
+    bind_group: Option<wgpu::BindGroup>,
+}
+
+/// the stencil reference text is drawn with inside the mask, see [`text_stencil_state`]
+const INSIDE: u32 = 1;
+
+/// glyphs pass where the stencil is at least the reference, everywhere with a reference of 0
+pub(crate) fn text_stencil_state() -> wgpu::DepthStencilState {
+    common::stencil_state(
+        wgpu::CompareFunction::LessEqual,
+        wgpu::StencilOperation::Keep,
+    )
+}
+
+impl ClipMask {
+    pub fn init(&mut self, device: &wgpu::Device, config: &wgpu::SurfaceConfiguration) {
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+            label: Some("mask bind group layout"),
+        });
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Mask Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("mask.wgsl").into()),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Mask Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = |entry_point, pass_op| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Mask Pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: config.format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::empty(),
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(common::stencil_state(
+                    wgpu::CompareFunction::Always,
+                    pass_op,
+                )),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+        self.clear_pipeline = Some(pipeline("fs_clear", wgpu::StencilOperation::Zero));
+        self.mask_pipeline = Some(pipeline("fs_main", wgpu::StencilOperation::Replace));
+        self.bind_group_layout = Some(layout);
+    }
+
+    pub fn set_clips(&mut self, clips: &[Clip]) {
+        self.clips = clips.to_vec();
+    }
+
+    /// draws the mask and sets the stencil reference the text is then drawn with
+    pub fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        if self.clips.is_empty() {
+            render_pass.set_stencil_reference(0);
+            return;
+        }
+        let resolution = [config.width as f32, config.height as f32];
+        let rect =
+            RectUniform::new((0., 0.), (0., 0.), [0.; 4], 1., resolution).with_clips(&self.clips);
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("mask buffer"),
+            contents: bytemuck::cast_slice(&[rect]),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        self.bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: self.bind_group_layout.as_ref().unwrap(),
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+            label: Some("mask bind group"),
+        }));
+
+        render_pass.set_bind_group(0, self.bind_group.as_ref().unwrap(), &[]);
+        render_pass.set_pipeline(self.clear_pipeline.as_ref().unwrap());
+        render_pass.draw(0..3, 0..1);
+        render_pass.set_stencil_reference(INSIDE);
+        render_pass.set_pipeline(self.mask_pipeline.as_ref().unwrap());
+        render_pass.draw(0..3, 0..1);
+    }
+}
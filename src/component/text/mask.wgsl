@@ -0,0 +1,81 @@
+// writes the shape of the ancestors clipping a text into the stencil, so the glyphs drawn after
+// it follow their rounded corners and transforms
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+};
+
+struct RectUniform {
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radii: vec4<f32>,
+    opacity: f32,
+    border_width: f32,
+    resolution: vec2<f32>,
+    border_color: vec4<f32>,
+    border_dashes: vec2<f32>,
+    border_align: u32,
+    clip_count: u32,
+    clips: array<vec4<f32>, 4>,
+    clip_radii: array<vec4<f32>, 4>,
+    inverse: array<vec4<f32>, 2>,
+    clip_inverses: array<vec4<f32>, 8>,
+}
+
+@group(0) @binding(0)
+var<uniform> rect: RectUniform;
+
+// one triangle over the whole window, the scissor rect keeps it to the clipped component
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let corner = vec2(f32((index << 1u) & 2u), f32(index & 2u));
+    out.position = vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+    return out;
+}
+
+fn rectSDF(p: vec2f, b: vec2f, r: f32) -> f32 {
+    let d = abs(p) - b + r;
+    return min(max(d.x, d.y), 0.0) + length(vec2(max(d.x, 0.0), max(d.y, 0.0))) - r;
+}
+
+// the radius of the corner nearest to `p`, `radii` go clockwise from the top left with y down
+fn cornerRadius(p: vec2f, radii: vec4f) -> f32 {
+    let side = select(radii.wz, radii.xy, p.y < 0.0);
+    return select(side.x, side.y, p.x > 0.0);
+}
+
+// maps the pixel `p` with the affine matrix given by its two rows
+fn transform(row0: vec4f, row1: vec4f, p: vec2f) -> vec2f {
+    return vec2(dot(row0.xyz, vec3(p, 1.0)), dot(row1.xyz, vec3(p, 1.0)));
+}
+
+// whether the pixel falls outside one of the ancestors clipping the component
+fn clipped(pixel: vec2f, ratio: f32) -> bool {
+    for (var i = 0u; i < rect.clip_count; i++) {
+        let clip = rect.clips[i];
+        let local = transform(rect.clip_inverses[i * 2], rect.clip_inverses[i * 2 + 1], pixel);
+        let uv = local / rect.resolution.xy;
+        let center = vec2(clip.x + clip.z / 2, clip.y - clip.w / 2.0);
+        let coords = (uv * 2 - 1 - vec2(center.x, -center.y)) * vec2(ratio, 1);
+        let half_size = clip.zw / 2 * vec2(ratio, 1);
+        if rectSDF(coords, half_size, cornerRadius(coords, rect.clip_radii[i])) > 0.0 {
+            return true;
+        }
+    }
+    return false;
+}
+
+// the pipeline clearing the stencil covers every pixel
+@fragment
+fn fs_clear(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4(0.0);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if clipped(in.position.xy, rect.resolution.x / rect.resolution.y) {
+        discard;
+    }
+    return vec4(0.0);
+}
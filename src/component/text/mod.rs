@@ -1,32 +1,49 @@
 mod api;
 mod config;
+mod highlight;
+pub(crate) mod layout;
+mod mask;
 mod renderer;
 
-use crate::context::Context;
+use glyphon::{Action, Cursor, Edit};
 
-use self::{config::TextConfig, renderer::TextRenderer};
+pub(crate) use self::{
+    config::TextConfig, highlight::Highlights, mask::ClipMask, renderer::TextRenderer,
+};
 
-use super::{Component, IntoComponent};
+use super::{Affine, Clip, Component, Handlers, IntoComponent, Transform, Transitions};
 
 #[derive(Default)]
 pub struct Text {
     renderer: Option<TextRenderer>,
     config: TextConfig,
     depth: i32,
-    on_click: Option<fn(Context)>,
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
     id: isize,
     size: (f32, f32),
+    selectable: bool,
+    highlights: Highlights,
+    mask: ClipMask,
 }
 
 impl Component for Text {
+    fn init_layout(&mut self, config: &wgpu::SurfaceConfiguration) {
+        self.renderer = Some(TextRenderer::new(config, &self.config));
+    }
+
     fn init(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         config: &wgpu::SurfaceConfiguration,
     ) {
-        let renderer = TextRenderer::new(device, queue, config, &self.config);
-        self.renderer = Some(renderer);
+        self.renderer.as_mut().unwrap().init(device, queue);
+        self.mask.init(device, config);
     }
 
     fn prepare(
@@ -39,26 +56,33 @@ impl Component for Text {
             .as_mut()
             .unwrap()
             .prepare(device, queue, config, &self.config);
+        if self.selectable {
+            self.layout_highlights(device, queue, config);
+        }
     }
 
     fn render<'a, 'b>(
         &'a mut self,
-        _device: &wgpu::Device,
-        _config: &wgpu::SurfaceConfiguration,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
         render_pass: &mut wgpu::RenderPass<'b>,
     ) where
         'a: 'b,
     {
-        self.renderer
-            .as_ref()
-            .unwrap()
-            .gl_renderer
-            .render(&self.renderer.as_ref().unwrap().atlas, render_pass)
-            .unwrap();
+        if self.selectable {
+            self.highlights.render(device, config, render_pass);
+        }
+        self.mask.render(device, config, render_pass);
+        self.renderer.as_ref().unwrap().render(render_pass);
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.mask.set_clips(clips);
+        self.highlights.set_clips(clips);
     }
 
     fn clean(&mut self) {
-        self.renderer.as_mut().unwrap().atlas.trim();
+        self.renderer.as_mut().unwrap().trim();
     }
 
     fn depth(&self) -> i32 {
@@ -86,8 +110,28 @@ impl Component for Text {
         self.size = size;
     }
 
-    fn click_handler(&self) -> Option<fn(Context)> {
-        self.on_click
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
     }
 
     fn get_id(&self) -> isize {
@@ -97,6 +141,137 @@ impl Component for Text {
     fn set_id(&mut self, id: isize) {
         self.id = id;
     }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable || self.selectable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, config: &wgpu::SurfaceConfiguration) {
+        self.config.follow(transform, config);
+    }
+
+    fn get_color(&self) -> Option<(f32, f32, f32, f32)> {
+        if self.config.gradient.is_some() {
+            return None;
+        }
+        let color = self.config.color;
+        let [r, g, b, a] = [color.r(), color.g(), color.b(), color.a()].map(|c| c as f32 / 255.);
+        Some((r, g, b, a))
+    }
+
+    fn set_color(&mut self, color: (f32, f32, f32, f32)) {
+        let [r, g, b, a] = [color.0, color.1, color.2, color.3].map(|c| (c * 255.) as u8);
+        self.config.color = glyphon::Color::rgba(r, g, b, a);
+        self.config.gradient = None;
+    }
+
+    fn set_focus(&mut self, focus: bool) {
+        if !focus {
+            if let Some(renderer) = self.renderer.as_mut() {
+                renderer.editor.set_select_opt(None);
+            }
+        }
+    }
+
+    fn copy(&self) -> Option<String> {
+        self.renderer
+            .as_ref()?
+            .editor
+            .copy_selection()
+            .filter(|content| !content.is_empty())
+    }
+
+    fn mouse_down(
+        &mut self,
+        cursor: (f32, f32),
+        click_count: u32,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> bool {
+        if !self.selectable {
+            return false;
+        }
+        let (x, y) = self.local_position(cursor, config);
+        let renderer = self.renderer.as_mut().unwrap();
+        renderer
+            .editor
+            .action(&mut renderer.font_system, Action::Click { x, y });
+        if click_count == 2 {
+            self.select_word();
+        }
+        true
+    }
+
+    fn mouse_drag(&mut self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        if !self.selectable {
+            return false;
+        }
+        let (x, y) = self.local_position(cursor, config);
+        let renderer = self.renderer.as_mut().unwrap();
+        renderer
+            .editor
+            .action(&mut renderer.font_system, Action::Drag { x, y });
+        true
+    }
+}
+
+impl Text {
+    /// converts a screen space cursor into pixels relative to the top left corner
+    fn local_position(
+        &self,
+        cursor: (f32, f32),
+        config: &wgpu::SurfaceConfiguration,
+    ) -> (i32, i32) {
+        (
+            ((cursor.0 - self.config.left) / 2. * config.width as f32) as i32,
+            ((self.config.top - cursor.1) / 2. * config.height as f32) as i32,
+        )
+    }
+
+    fn select_word(&mut self) {
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        let cursor = editor.cursor();
+        let text = editor.buffer().lines[cursor.line].text();
+        let (start, end) = layout::word_at(text, cursor.index);
+        editor.set_select_opt(Some(Cursor::new(cursor.line, start)));
+        editor.set_cursor(Cursor::new(cursor.line, end));
+    }
+
+    fn layout_highlights(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let text_config = self.config.fit_screen(config);
+        let editor = &self.renderer.as_ref().unwrap().editor;
+        let spans = match editor.select_opt() {
+            Some(select) => layout::selection_spans(editor.buffer(), select, editor.cursor()),
+            None => vec![],
+        };
+        let line_height = editor.buffer().metrics().line_height;
+        self.highlights
+            .layout(device, queue, config, &text_config, &spans, line_height);
+    }
 }
 
 impl IntoComponent for Text {
@@ -1,32 +1,26 @@
 use glyphon::{
-    Attrs, Buffer, Family, FontSystem, Metrics, Shaping, SwashCache, TextArea, TextAtlas,
-    TextRenderer as GlTextRenderer,
+    Attrs, AttrsList, Buffer, Color, Edit, Editor, Family, FontSystem, Metrics, Shaping,
+    SwashCache, TextArea, TextAtlas, TextRenderer as GlTextRenderer,
 };
 use wgpu::TextureFormat;
 
-use super::config::TextConfig;
+use crate::component::Fill;
+
+use super::{config::TextConfig, mask};
 
 pub struct TextRenderer {
     pub cache: SwashCache,
-    pub gl_renderer: GlTextRenderer,
     pub font_system: FontSystem,
-    pub atlas: TextAtlas,
-    pub buffer: Buffer,
+    pub editor: Editor,
+    /// the glyph atlas and the renderer drawing from it, `None` until [`TextRenderer::init`]
+    gpu: Option<(TextAtlas, GlTextRenderer)>,
 }
 
 impl TextRenderer {
-    pub fn new(
-        device: &wgpu::Device,
-        queue: &wgpu::Queue,
-        config: &wgpu::SurfaceConfiguration,
-        text_config: &TextConfig,
-    ) -> Self {
-        let format = TextureFormat::Bgra8UnormSrgb;
+    /// lays out the text without drawing it until [`TextRenderer::init`] is called
+    pub fn new(config: &wgpu::SurfaceConfiguration, text_config: &TextConfig) -> Self {
         let mut font_system = FontSystem::new();
         let cache = SwashCache::new();
-        let mut atlas = TextAtlas::new(device, queue, format);
-        let gl_renderer =
-            GlTextRenderer::new(&mut atlas, device, wgpu::MultisampleState::default(), None);
 
         let mut buffer = Buffer::new(&mut font_system, Metrics::new(30.0, 42.0));
         let physical_width = config.width as f32;
@@ -41,14 +35,40 @@ impl TextRenderer {
         buffer.shape_until_scroll(&mut font_system);
 
         Self {
-            gl_renderer,
             cache,
             font_system,
-            atlas,
-            buffer,
+            editor: Editor::new(buffer),
+            gpu: None,
         }
     }
 
+    pub fn init(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let format = TextureFormat::Bgra8UnormSrgb;
+        let mut atlas = TextAtlas::new(device, queue, format);
+        let gl_renderer = GlTextRenderer::new(
+            &mut atlas,
+            device,
+            wgpu::MultisampleState::default(),
+            Some(mask::text_stencil_state()),
+        );
+        self.gpu = Some((atlas, gl_renderer));
+    }
+
+    pub fn buffer(&self) -> &Buffer {
+        self.editor.buffer()
+    }
+
+    pub fn set_content(&mut self, content: &str) {
+        let buffer = self.editor.buffer_mut();
+        buffer.set_text(
+            &mut self.font_system,
+            content,
+            Attrs::new().family(Family::SansSerif),
+            Shaping::Advanced,
+        );
+        buffer.shape_until_scroll(&mut self.font_system);
+    }
+
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -56,19 +76,21 @@ impl TextRenderer {
         config: &wgpu::SurfaceConfiguration,
         text_config: &TextConfig,
     ) {
+        self.paint(text_config.gradient.as_ref());
         let text_config = text_config.fit_screen(config);
-        self.gl_renderer
+        let (atlas, gl_renderer) = self.gpu.as_mut().unwrap();
+        gl_renderer
             .prepare(
                 device,
                 queue,
                 &mut self.font_system,
-                &mut self.atlas,
+                atlas,
                 glyphon::Resolution {
                     width: config.width,
                     height: config.height,
                 },
                 [TextArea {
-                    buffer: &self.buffer,
+                    buffer: self.editor.buffer(),
                     left: text_config.left,
                     top: text_config.top,
                     scale: text_config.scale,
@@ -79,4 +101,61 @@ impl TextRenderer {
             )
             .unwrap();
     }
+
+    pub fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
+        let (atlas, gl_renderer) = self.gpu.as_ref().unwrap();
+        gl_renderer.render(atlas, render_pass).unwrap();
+    }
+
+    /// frees the glyphs that were not drawn in the last frame
+    pub fn trim(&mut self) {
+        if let Some((atlas, _)) = self.gpu.as_mut() {
+            atlas.trim();
+        }
+    }
+
+    /// colors every glyph with `fill` at its center in the box the text is laid out in, or
+    /// back to the default color without one, shaping again only the lines that changed
+    fn paint(&mut self, fill: Option<&Fill>) {
+        let buffer = self.editor.buffer();
+        let line_height = buffer.metrics().line_height;
+        let mut width: f32 = 0.;
+        let mut height: f32 = 0.;
+        for run in buffer.layout_runs() {
+            width = width.max(run.line_w);
+            height = height.max(run.line_top + line_height);
+        }
+        let half = (width / 2., height / 2.);
+        let attrs = Attrs::new().family(Family::SansSerif);
+        // the spans of each line and whether any glyph does not look right yet
+        let mut lines: Vec<(usize, AttrsList, bool)> = vec![];
+        for run in buffer.layout_runs() {
+            if lines.last().map(|line| line.0) != Some(run.line_i) {
+                lines.push((run.line_i, AttrsList::new(attrs), false));
+            }
+            let (_, attrs_list, stale) = lines.last_mut().unwrap();
+            for glyph in run.glyphs {
+                let color = fill.map(|fill| {
+                    let center = (glyph.x + glyph.w / 2., run.line_top + line_height / 2.);
+                    let (r, g, b, a) = fill.color_at((center.0 - half.0, center.1 - half.1), half);
+                    let [r, g, b, a] = [r, g, b, a].map(|c| (c.clamp(0., 1.) * 255.) as u8);
+                    Color::rgba(r, g, b, a)
+                });
+                *stale |= glyph.color_opt != color;
+                if let Some(color) = color {
+                    attrs_list.add_span(glyph.start..glyph.end, attrs.color(color));
+                }
+            }
+        }
+        let buffer = self.editor.buffer_mut();
+        let mut changed = false;
+        for (line_i, attrs_list, stale) in lines {
+            if stale {
+                changed |= buffer.lines[line_i].set_attrs_list(attrs_list);
+            }
+        }
+        if changed {
+            buffer.shape_until_scroll(&mut self.font_system);
+        }
+    }
 }
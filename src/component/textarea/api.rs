@@ -0,0 +1,79 @@
+use glyphon::Wrap;
+
+use super::TextArea;
+
+impl TextArea {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width * 2., height * 2.);
+        self
+    }
+
+    pub fn color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.config.color = glyphon::Color::rgba(
+            (r * 255.) as u8,
+            (g * 255.) as u8,
+            (b * 255.) as u8,
+            (a * 255.) as u8,
+        );
+        self.caret = self.caret.color(r, g, b, a);
+        self.underline.set_color((r, g, b, a));
+        self
+    }
+
+    pub fn highlight_color(mut self, r: f32, g: f32, b: f32, a: f32) -> Self {
+        self.highlights.set_color((r, g, b, a));
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.config.content = content.into();
+        self
+    }
+
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.config.left = x * 2. - 1.;
+        self.config.top = -(y * 2. - 1.);
+        self
+    }
+
+    pub fn depth(mut self, depth: i32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// wrap lines at word boundaries to the width of the area, enabled by default
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = match wrap {
+            true => Wrap::Word,
+            false => Wrap::None,
+        };
+        self
+    }
+
+    /// number of spaces inserted by the Tab key
+    pub fn tab_size(mut self, tab_size: usize) -> Self {
+        self.tab_size = tab_size;
+        self
+    }
+
+    /// the committed content, without the text being composed with the input method
+    pub fn value(&self) -> String {
+        let Some(renderer) = &self.renderer else {
+            return self.config.content.clone();
+        };
+        let mut lines: Vec<String> = renderer
+            .buffer()
+            .lines
+            .iter()
+            .map(|line| line.text().to_string())
+            .collect();
+        if let Some(preedit) = &self.preedit {
+            lines[preedit.start.line].replace_range(preedit.start.index..preedit.end.index, "");
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,255 @@
+use glyphon::{Action, Cursor, Edit};
+use winit::{
+    event::{ElementState, Ime},
+    keyboard::{Key, NamedKey},
+};
+
+use crate::{component::text::layout, event::KeyboardEvent};
+
+use super::{history::Change, Preedit, TextArea};
+
+impl TextArea {
+    pub(crate) fn handle_key(&mut self, event: &KeyboardEvent) -> bool {
+        // while composing, keys belong to the input method
+        if event.state != ElementState::Pressed || self.preedit.is_some() {
+            return false;
+        }
+        let shift = event.modifiers.shift_key();
+        let ctrl = event.modifiers.control_key() || event.modifiers.super_key();
+        match &event.key {
+            Key::Character(c) if ctrl => match c.to_lowercase().as_str() {
+                "z" if shift => self.redo(),
+                "z" => self.undo(),
+                "y" => self.redo(),
+                "a" => self.select_all(),
+                _ => return false,
+            },
+            Key::Named(NamedKey::Backspace) => self.delete(false),
+            Key::Named(NamedKey::Delete) => self.delete(true),
+            Key::Named(NamedKey::Enter) => self.insert("\n"),
+            Key::Named(NamedKey::Tab) if !ctrl => self.insert(&" ".repeat(self.tab_size)),
+            Key::Named(NamedKey::Escape) => {
+                let editor = &mut self.renderer.as_mut().unwrap().editor;
+                if editor.select_opt().is_none() {
+                    return false;
+                }
+                editor.set_select_opt(None);
+            }
+            Key::Named(key) => {
+                let action = match key {
+                    NamedKey::ArrowLeft if ctrl => Action::LeftWord,
+                    NamedKey::ArrowRight if ctrl => Action::RightWord,
+                    NamedKey::ArrowLeft => Action::Left,
+                    NamedKey::ArrowRight => Action::Right,
+                    NamedKey::ArrowUp => Action::Up,
+                    NamedKey::ArrowDown => Action::Down,
+                    NamedKey::Home if ctrl => Action::BufferStart,
+                    NamedKey::End if ctrl => Action::BufferEnd,
+                    NamedKey::Home => Action::Home,
+                    NamedKey::End => Action::End,
+                    NamedKey::PageUp => Action::PageUp,
+                    NamedKey::PageDown => Action::PageDown,
+                    NamedKey::Space => {
+                        self.insert(" ");
+                        return true;
+                    }
+                    _ => return false,
+                };
+                self.select_with(shift);
+                self.action(action);
+            }
+            _ => {
+                let text: String = event
+                    .text
+                    .as_ref()
+                    .map(|text| text.chars().filter(|c| !c.is_control()).collect())
+                    .unwrap_or_default();
+                if ctrl || text.is_empty() {
+                    return false;
+                }
+                self.insert(&text);
+            }
+        }
+        true
+    }
+
+    pub(crate) fn handle_ime(&mut self, ime: &Ime) -> bool {
+        match ime {
+            Ime::Enabled => return false,
+            Ime::Preedit(content, cursor) => {
+                self.clear_preedit();
+                let content: String = content.chars().filter(|c| !c.is_control()).collect();
+                if !content.is_empty() {
+                    self.show_preedit(&content, *cursor);
+                }
+            }
+            Ime::Commit(content) => {
+                self.clear_preedit();
+                self.insert(content);
+            }
+            Ime::Disabled => self.clear_preedit(),
+        }
+        true
+    }
+
+    /// shows the composing text at the cursor, replacing the selection, without recording it in
+    /// the history
+    fn show_preedit(&mut self, content: &str, cursor: Option<(usize, usize)>) {
+        let (start, end) = self.selection();
+        if (start.line, start.index) != (end.line, end.index) {
+            self.insert("");
+        }
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        editor.set_select_opt(None);
+        editor.insert_string(content, None);
+        let end = editor.cursor();
+        if let Some((index, _)) = cursor {
+            editor.set_cursor(Cursor::new(start.line, start.index + index));
+        }
+        editor.buffer_mut().set_redraw(true);
+        self.preedit = Some(Preedit {
+            start: Cursor::new(start.line, start.index),
+            end,
+            caret: cursor.is_some(),
+        });
+    }
+
+    /// removes the composing text, leaving the cursor where it started
+    pub(crate) fn clear_preedit(&mut self) {
+        let Some(preedit) = self.preedit.take() else {
+            return;
+        };
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        editor.set_cursor(preedit.start);
+        editor.set_select_opt(Some(preedit.end));
+        editor.delete_selection();
+        editor.set_select_opt(None);
+        editor.buffer_mut().set_redraw(true);
+    }
+
+    /// scrolls the buffer by `delta` pixels down, returns false if it cannot move that way
+    pub(crate) fn scroll(&mut self, delta: f32) -> bool {
+        if delta == 0. {
+            return false;
+        }
+        let renderer = self.renderer.as_mut().unwrap();
+        let line_height = renderer.buffer().metrics().line_height;
+        let buffer = renderer.editor.buffer_mut();
+        let scroll = buffer.scroll();
+        // the buffer clamps the scroll to its content, try a line first to find out if it moves
+        buffer.set_scroll(scroll + delta.signum() as i32);
+        buffer.shape_until_scroll(&mut renderer.font_system);
+        if buffer.scroll() == scroll {
+            self.scroll_remainder = 0.;
+            return false;
+        }
+        self.scroll_remainder += delta / line_height;
+        let lines = self.scroll_remainder.trunc();
+        self.scroll_remainder -= lines;
+        buffer.set_scroll(scroll + lines as i32);
+        buffer.shape_until_scroll(&mut renderer.font_system);
+        true
+    }
+
+    pub(crate) fn action(&mut self, action: Action) {
+        let renderer = self.renderer.as_mut().unwrap();
+        renderer.editor.action(&mut renderer.font_system, action);
+    }
+
+    /// starts or extends a selection before a cursor movement when shift is held
+    fn select_with(&mut self, shift: bool) {
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        match (shift, editor.select_opt()) {
+            (true, None) => editor.set_select_opt(Some(editor.cursor())),
+            (false, Some(_)) => editor.set_select_opt(None),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn select_word(&mut self) {
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        let cursor = editor.cursor();
+        let text = editor.buffer().lines[cursor.line].text();
+        let (start, end) = layout::word_at(text, cursor.index);
+        editor.set_select_opt(Some(Cursor::new(cursor.line, start)));
+        editor.set_cursor(Cursor::new(cursor.line, end));
+    }
+
+    fn select_all(&mut self) {
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        let lines = &editor.buffer().lines;
+        let end = Cursor::new(lines.len() - 1, lines[lines.len() - 1].text().len());
+        editor.set_select_opt(Some(Cursor::new(0, 0)));
+        editor.set_cursor(end);
+    }
+
+    /// the current selection, or an empty range at the cursor
+    fn selection(&self) -> (Cursor, Cursor) {
+        let editor = &self.renderer.as_ref().unwrap().editor;
+        let cursor = editor.cursor();
+        layout::ordered(editor.select_opt().unwrap_or(cursor), cursor)
+    }
+
+    pub(crate) fn insert(&mut self, text: &str) {
+        let (start, end) = self.selection();
+        let change = self.replace(start, end, text);
+        self.history.push(change);
+    }
+
+    /// deletes the selection, or the character before (or after, if `forward`) the cursor
+    fn delete(&mut self, forward: bool) {
+        let (mut start, mut end) = self.selection();
+        if (start.line, start.index) == (end.line, end.index) {
+            let lines = &self.renderer.as_ref().unwrap().editor.buffer().lines;
+            let text = lines[start.line].text();
+            match forward {
+                true => match text[start.index..].chars().next() {
+                    Some(c) => end.index += c.len_utf8(),
+                    None if start.line + 1 < lines.len() => end = Cursor::new(start.line + 1, 0),
+                    None => return,
+                },
+                false => match text[..start.index].chars().next_back() {
+                    Some(c) => start.index -= c.len_utf8(),
+                    None if start.line > 0 => {
+                        start = Cursor::new(start.line - 1, lines[start.line - 1].text().len())
+                    }
+                    None => return,
+                },
+            }
+        }
+        let change = self.replace(start, end, "");
+        self.history.push(change);
+    }
+
+    fn undo(&mut self) {
+        if let Some(change) = self.history.undo() {
+            self.apply(&change);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(change) = self.history.redo() {
+            self.apply(&change);
+        }
+    }
+
+    fn apply(&mut self, change: &Change) {
+        self.replace(change.start, change.removed_end(), &change.inserted);
+    }
+
+    /// replaces the text between `start` and `end` and returns the change for the history
+    fn replace(&mut self, start: Cursor, end: Cursor, text: &str) -> Change {
+        let editor = &mut self.renderer.as_mut().unwrap().editor;
+        editor.set_cursor(start);
+        editor.set_select_opt(Some(end));
+        let removed = editor.copy_selection().unwrap_or_default();
+        editor.delete_selection();
+        editor.insert_string(text, None);
+        editor.buffer_mut().set_redraw(true);
+        Change {
+            start: Cursor::new(start.line, start.index),
+            removed,
+            inserted: text.into(),
+        }
+    }
+}
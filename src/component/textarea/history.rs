@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use glyphon::Cursor;
+
+const MAX_HISTORY: usize = 1000;
+
+/// A single replacement of `removed` by `inserted` starting at `start`.
+///
+/// Only the touched text is stored, so the cost of an entry does not depend on document size.
+#[derive(Clone, Debug)]
+pub(crate) struct Change {
+    pub start: Cursor,
+    pub removed: String,
+    pub inserted: String,
+}
+
+impl Change {
+    /// the change that reverts this one
+    pub fn invert(&self) -> Self {
+        Self {
+            start: self.start,
+            removed: self.inserted.clone(),
+            inserted: self.removed.clone(),
+        }
+    }
+
+    /// cursor right after the inserted text
+    pub fn inserted_end(&self) -> Cursor {
+        end_of(self.start, &self.inserted)
+    }
+
+    /// cursor right after the removed text, in the document before the change
+    pub fn removed_end(&self) -> Cursor {
+        end_of(self.start, &self.removed)
+    }
+
+    /// typing a word produces one undo step instead of one per character
+    fn merge(&mut self, next: &Change) -> bool {
+        let typing = self.removed.is_empty() && next.removed.is_empty();
+        let end = self.inserted_end();
+        let contiguous = (end.line, end.index) == (next.start.line, next.start.index);
+        let same_word = next.inserted.chars().all(|c| !c.is_whitespace())
+            && self.inserted.chars().all(|c| !c.is_whitespace());
+        if typing && contiguous && same_word {
+            self.inserted.push_str(&next.inserted);
+            return true;
+        }
+        false
+    }
+}
+
+fn end_of(start: Cursor, text: &str) -> Cursor {
+    match text.rfind('\n') {
+        Some(last) => Cursor::new(
+            start.line + text.matches('\n').count(),
+            text.len() - last - 1,
+        ),
+        None => Cursor::new(start.line, start.index + text.len()),
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct History {
+    undo: VecDeque<Change>,
+    redo: Vec<Change>,
+}
+
+impl History {
+    pub fn push(&mut self, change: Change) {
+        self.redo.clear();
+        if let Some(last) = self.undo.back_mut() {
+            if last.merge(&change) {
+                return;
+            }
+        }
+        if self.undo.len() == MAX_HISTORY {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(change);
+    }
+
+    /// returns the change to apply in order to undo the last edit
+    pub fn undo(&mut self) -> Option<Change> {
+        let change = self.undo.pop_back()?;
+        let inverse = change.invert();
+        self.redo.push(change);
+        Some(inverse)
+    }
+
+    /// returns the change to apply in order to redo the last undone edit
+    pub fn redo(&mut self) -> Option<Change> {
+        let change = self.redo.pop()?;
+        self.undo.push_back(change.clone());
+        Some(change)
+    }
+}
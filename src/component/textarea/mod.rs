@@ -0,0 +1,400 @@
+mod api;
+mod edit;
+mod history;
+
+use glyphon::{Cursor, Edit, TextBounds, Wrap};
+use winit::event::Ime;
+
+use crate::event::KeyboardEvent;
+
+use self::history::History;
+
+use super::{
+    text::{layout, ClipMask, Highlights, TextConfig, TextRenderer},
+    Affine, Clip, Component, Handlers, IntoComponent, Rect, Transform, Transitions,
+};
+
+/// Text being composed with the input method, shown in the buffer but not yet committed.
+struct Preedit {
+    start: Cursor,
+    end: Cursor,
+    /// whether the input method shows a caret within the composing text
+    caret: bool,
+}
+
+pub struct TextArea {
+    renderer: Option<TextRenderer>,
+    config: TextConfig,
+    history: History,
+    preedit: Option<Preedit>,
+    /// wheel movement in lines not scrolled yet
+    scroll_remainder: f32,
+    wrap: Wrap,
+    tab_size: usize,
+    focused: bool,
+    caret: Rect,
+    highlights: Highlights,
+    underline: Highlights,
+    mask: ClipMask,
+    depth: i32,
+    id: isize,
+    size: (f32, f32),
+    handlers: Handlers,
+    transitions: Transitions,
+    hidden: bool,
+    focusable: bool,
+    draggable: bool,
+    transform: Transform,
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self {
+            renderer: None,
+            config: TextConfig::default(),
+            history: History::default(),
+            preedit: None,
+            scroll_remainder: 0.,
+            wrap: Wrap::Word,
+            tab_size: 4,
+            focused: false,
+            caret: Rect::new().color(1., 1., 1., 1.),
+            highlights: Highlights::default(),
+            underline: {
+                let mut underline = Highlights::default();
+                underline.set_color((1., 1., 1., 1.));
+                underline
+            },
+            mask: ClipMask::default(),
+            depth: 0,
+            id: 0,
+            size: (2., 2.),
+            handlers: Handlers::default(),
+            transitions: Transitions::default(),
+            hidden: false,
+            focusable: true,
+            draggable: false,
+            transform: Transform::default(),
+        }
+    }
+}
+
+impl Component for TextArea {
+    fn init_layout(&mut self, config: &wgpu::SurfaceConfiguration) {
+        let (width, height) = self.pixel_size(config);
+        let mut renderer = TextRenderer::new(config, &self.config);
+        let buffer = renderer.editor.buffer_mut();
+        buffer.set_wrap(&mut renderer.font_system, self.wrap);
+        buffer.set_size(&mut renderer.font_system, width, height);
+        self.renderer = Some(renderer);
+    }
+
+    fn init(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.renderer.as_mut().unwrap().init(device, queue);
+        self.caret.init(device, queue, config);
+        self.mask.init(device, config);
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let (width, height) = self.pixel_size(config);
+        let text_config = self.config.fit_screen(config);
+        let scale = text_config.scale;
+        self.config.text_bounds = TextBounds {
+            left: text_config.left as i32,
+            top: text_config.top as i32,
+            right: (text_config.left + width * scale) as i32,
+            bottom: (text_config.top + height * scale) as i32,
+        };
+        let renderer = self.renderer.as_mut().unwrap();
+        renderer
+            .editor
+            .buffer_mut()
+            .set_size(&mut renderer.font_system, width, height);
+        renderer.editor.shape_as_needed(&mut renderer.font_system);
+        renderer.prepare(device, queue, config, &self.config);
+        self.layout_decorations(device, queue, config);
+    }
+
+    fn render<'a, 'b>(
+        &'a mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'b>,
+    ) where
+        'a: 'b,
+    {
+        let show_caret = self.focused
+            && self.preedit.as_ref().is_none_or(|preedit| preedit.caret)
+            && self.caret_position().is_some();
+        self.highlights.render(device, config, render_pass);
+        self.mask.render(device, config, render_pass);
+        self.renderer.as_ref().unwrap().render(render_pass);
+        if show_caret {
+            self.caret.render(device, config, render_pass);
+        }
+        self.underline.render(device, config, render_pass);
+    }
+
+    fn set_clips(&mut self, clips: &[Clip]) {
+        self.mask.set_clips(clips);
+        self.highlights.set_clips(clips);
+        self.underline.set_clips(clips);
+        self.caret.set_clips(clips);
+    }
+
+    fn clean(&mut self) {
+        self.renderer.as_mut().unwrap().trim();
+    }
+
+    fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: i32) {
+        self.depth = depth;
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        (self.config.left, self.config.top)
+    }
+
+    fn set_position(&mut self, position: (f32, f32)) {
+        self.config.left = position.0;
+        self.config.top = position.1;
+    }
+
+    fn get_size(&self) -> (f32, f32) {
+        self.size
+    }
+
+    fn set_size(&mut self, size: (f32, f32)) {
+        self.size = size;
+    }
+
+    fn get_id(&self) -> isize {
+        self.id
+    }
+
+    fn set_id(&mut self, id: isize) {
+        self.id = id;
+    }
+
+    fn handlers(&self) -> &Handlers {
+        &self.handlers
+    }
+
+    fn handlers_mut(&mut self) -> &mut Handlers {
+        &mut self.handlers
+    }
+
+    fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    fn transitions_mut(&mut self) -> &mut Transitions {
+        &mut self.transitions
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    fn set_focusable(&mut self, focusable: bool) {
+        self.focusable = focusable;
+    }
+
+    fn is_draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn set_draggable(&mut self, draggable: bool) {
+        self.draggable = draggable;
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn set_world_transform(&mut self, transform: Affine, config: &wgpu::SurfaceConfiguration) {
+        self.config.follow(transform, config);
+    }
+
+    fn set_focus(&mut self, focus: bool) {
+        self.focused = focus;
+        if !focus {
+            self.clear_preedit();
+        }
+    }
+
+    fn ime_allowed(&self) -> bool {
+        true
+    }
+
+    fn keyboard_input(&mut self, event: &KeyboardEvent) -> bool {
+        self.handle_key(event)
+    }
+
+    fn ime(&mut self, ime: &Ime) -> bool {
+        self.handle_ime(ime)
+    }
+
+    fn copy(&self) -> Option<String> {
+        self.selected_text()
+    }
+
+    fn cut(&mut self) -> Option<String> {
+        let content = self.selected_text()?;
+        self.insert("");
+        Some(content)
+    }
+
+    fn paste(&mut self, content: &str) -> bool {
+        if self.preedit.is_some() {
+            return false;
+        }
+        self.insert(content);
+        true
+    }
+
+    fn mouse_down(
+        &mut self,
+        cursor: (f32, f32),
+        click_count: u32,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> bool {
+        self.clear_preedit();
+        let (x, y) = self.local_position(cursor, config);
+        self.action(glyphon::Action::Click { x, y });
+        if click_count == 2 {
+            self.select_word();
+        }
+        true
+    }
+
+    fn mouse_drag(&mut self, cursor: (f32, f32), config: &wgpu::SurfaceConfiguration) -> bool {
+        let (x, y) = self.local_position(cursor, config);
+        self.action(glyphon::Action::Drag { x, y });
+        true
+    }
+
+    fn wheel(&mut self, delta: (f32, f32)) -> bool {
+        self.scroll(delta.1)
+    }
+
+    fn ime_cursor_area(
+        &self,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Option<((f32, f32), (f32, f32))> {
+        let (x, y) = self.caret_position()?;
+        let text_config = self.config.fit_screen(config);
+        let scale = text_config.scale;
+        let metrics = self.renderer.as_ref()?.buffer().metrics();
+        Some((
+            (text_config.left + x * scale, text_config.top + y * scale),
+            (metrics.font_size * scale, metrics.line_height * scale),
+        ))
+    }
+}
+
+impl TextArea {
+    fn pixel_size(&self, config: &wgpu::SurfaceConfiguration) -> (f32, f32) {
+        (
+            self.size.0 / 2. * config.width as f32,
+            self.size.1 / 2. * config.height as f32,
+        )
+    }
+
+    /// converts a screen space cursor into pixels relative to the top left corner
+    fn local_position(
+        &self,
+        cursor: (f32, f32),
+        config: &wgpu::SurfaceConfiguration,
+    ) -> (i32, i32) {
+        (
+            ((cursor.0 - self.config.left) / 2. * config.width as f32) as i32,
+            ((self.config.top - cursor.1) / 2. * config.height as f32) as i32,
+        )
+    }
+
+    fn selected_text(&self) -> Option<String> {
+        if self.preedit.is_some() {
+            return None;
+        }
+        self.renderer
+            .as_ref()?
+            .editor
+            .copy_selection()
+            .filter(|content| !content.is_empty())
+    }
+
+    fn caret_position(&self) -> Option<(f32, f32)> {
+        let editor = &self.renderer.as_ref()?.editor;
+        layout::cursor_position(editor.buffer(), editor.cursor())
+    }
+
+    fn layout_decorations(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let (width, height) = (config.width as f32, config.height as f32);
+        let to_ndc = |x: f32, y: f32| (x / width * 2. - 1., 1. - y / height * 2.);
+        let text_config = self.config.fit_screen(config);
+        let editor = &self.renderer.as_ref().unwrap().editor;
+        let line_height = editor.buffer().metrics().line_height;
+        let spans = match editor.select_opt() {
+            Some(select) => layout::selection_spans(editor.buffer(), select, editor.cursor()),
+            None => vec![],
+        };
+        self.highlights
+            .layout(device, queue, config, &text_config, &spans, line_height);
+        let underline: Vec<_> = match &self.preedit {
+            Some(preedit) => layout::selection_spans(editor.buffer(), preedit.start, preedit.end)
+                .into_iter()
+                .map(|(x, y, span)| (x, y + line_height - 2., span))
+                .collect(),
+            None => vec![],
+        };
+        self.underline
+            .layout(device, queue, config, &text_config, &underline, 2.);
+
+        if let Some((x, y)) = self.caret_position() {
+            let scale = text_config.scale;
+            self.caret.set_position(to_ndc(
+                text_config.left + x * scale,
+                text_config.top + y * scale,
+            ));
+            self.caret
+                .set_size((2. / width * 2., line_height * scale / height * 2.));
+        }
+    }
+}
+
+impl IntoComponent for TextArea {
+    fn into_comp(self) -> super::Comp {
+        super::Comp::TextArea(self)
+    }
+}
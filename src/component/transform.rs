@@ -0,0 +1,168 @@
+/// Rotation, scale, skew and translation of a component and everything inside it.
+///
+/// The transform is applied around `origin` and composes with the transforms of the ancestors.
+/// Text is moved and evenly scaled along with its transform but its glyphs are drawn upright: a
+/// rotation, skew, mirror or uneven scale of the text, an ancestor or a camera is not applied to
+/// them and is logged as a warning once per text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// in physical pixels, y pointing down
+    pub translation: (f32, f32),
+    /// clockwise, in radians
+    pub rotation: f32,
+    pub scale: (f32, f32),
+    /// horizontal and vertical shear angles in radians
+    pub skew: (f32, f32),
+    /// the fixed point of rotation, scale and skew, in fractions of the size from the top left
+    /// corner
+    pub origin: (f32, f32),
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translation: (0., 0.),
+            rotation: 0.,
+            scale: (1., 1.),
+            skew: (0., 0.),
+            origin: (0.5, 0.5),
+        }
+    }
+}
+
+impl Transform {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn translate(mut self, x: f32, y: f32) -> Self {
+        self.translation = (x, y);
+        self
+    }
+
+    pub fn rotate(mut self, radians: f32) -> Self {
+        self.rotation = radians;
+        self
+    }
+
+    pub fn scale(mut self, x: f32, y: f32) -> Self {
+        self.scale = (x, y);
+        self
+    }
+
+    pub fn skew(mut self, x: f32, y: f32) -> Self {
+        self.skew = (x, y);
+        self
+    }
+
+    pub fn origin(mut self, x: f32, y: f32) -> Self {
+        self.origin = (x, y);
+        self
+    }
+
+    /// the matrix of the transform for a component with `bounds` given as left, top, width and
+    /// height in physical pixels
+    pub(crate) fn matrix(&self, bounds: (f32, f32, f32, f32)) -> Affine {
+        if *self == Transform::default() {
+            return Affine::IDENTITY;
+        }
+        let (left, top, width, height) = bounds;
+        let origin = (left + width * self.origin.0, top + height * self.origin.1);
+        let (sin, cos) = self.rotation.sin_cos();
+        Affine::translation(origin.0 + self.translation.0, origin.1 + self.translation.1)
+            * Affine([cos, -sin, 0., sin, cos, 0.])
+            * Affine([1., self.skew.0.tan(), 0., self.skew.1.tan(), 1., 0.])
+            * Affine([self.scale.0, 0., 0., 0., self.scale.1, 0.])
+            * Affine::translation(-origin.0, -origin.1)
+    }
+}
+
+/// A 2D affine map in physical pixels, row major, taking `(x, y)` to
+/// `(a x + b y + c, d x + e y + f)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine(pub [f32; 6]);
+
+impl Affine {
+    pub const IDENTITY: Affine = Affine([1., 0., 0., 0., 1., 0.]);
+
+    pub fn translation(x: f32, y: f32) -> Self {
+        Affine([1., 0., x, 0., 1., y])
+    }
+
+    pub fn apply(&self, point: (f32, f32)) -> (f32, f32) {
+        let [a, b, c, d, e, f] = self.0;
+        (a * point.0 + b * point.1 + c, d * point.0 + e * point.1 + f)
+    }
+
+    /// `None` if the map flattens everything onto a line or point
+    pub fn inverse(&self) -> Option<Affine> {
+        let [a, b, c, d, e, f] = self.0;
+        let det = a * e - b * d;
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+        let (a, b, d, e) = (e / det, -b / det, -d / det, a / det);
+        Some(Affine([a, b, -(a * c + b * f), d, e, -(d * c + e * f)]))
+    }
+
+    /// the map applied to a point given in screen space, returning screen space
+    pub fn apply_ndc(&self, point: (f32, f32), config: &wgpu::SurfaceConfiguration) -> (f32, f32) {
+        if *self == Affine::IDENTITY {
+            return point;
+        }
+        let (width, height) = (config.width as f32, config.height as f32);
+        let pixel = ((point.0 + 1.) / 2. * width, (1. - point.1) / 2. * height);
+        let (x, y) = self.apply(pixel);
+        (x / width * 2. - 1., 1. - y / height * 2.)
+    }
+
+    /// the smallest axis aligned box around the map of the box `bounds`, given and returned as
+    /// left, top, width and height
+    pub fn bounding_box(&self, bounds: (f32, f32, f32, f32)) -> (f32, f32, f32, f32) {
+        let (left, top, width, height) = bounds;
+        let corners = [
+            (left, top),
+            (left + width, top),
+            (left + width, top + height),
+            (left, top + height),
+        ]
+        .map(|corner| self.apply(corner));
+        let (min_x, max_x) = corners.iter().fold((f32::MAX, f32::MIN), |(min, max), p| {
+            (min.min(p.0), max.max(p.0))
+        });
+        let (min_y, max_y) = corners.iter().fold((f32::MAX, f32::MIN), |(min, max), p| {
+            (min.min(p.1), max.max(p.1))
+        });
+        (min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
+    /// the two rows of the matrix padded for a uniform buffer
+    pub fn rows(&self) -> [[f32; 4]; 2] {
+        let [a, b, c, d, e, f] = self.0;
+        [[a, b, c, 0.], [d, e, f, 0.]]
+    }
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Affine::IDENTITY
+    }
+}
+
+impl std::ops::Mul for Affine {
+    type Output = Affine;
+
+    /// the map applying `rhs` first and `self` second
+    fn mul(self, rhs: Affine) -> Affine {
+        let [a, b, c, d, e, f] = self.0;
+        let [g, h, i, j, k, l] = rhs.0;
+        Affine([
+            a * g + b * j,
+            a * h + b * k,
+            a * i + b * l + c,
+            d * g + e * j,
+            d * h + e * k,
+            d * i + e * l + f,
+        ])
+    }
+}
@@ -1,27 +1,277 @@
-use winit::window::Fullscreen;
+use std::{path::Path, time::Instant};
 
-use crate::state::State;
+use winit::{event::TouchPhase, window::Fullscreen};
+
+use crate::{
+    animation::{window_position, window_size, Animation, AnimationId, Property, Spring},
+    clipboard::Clipboard,
+    component::{Comp, Component, IntoComponent, Transform},
+    event::PointerConfig,
+    shortcut::Shortcuts,
+    state::State,
+};
 
 pub struct Context<'a>(pub(crate) &'a mut State);
 
 impl<'a> Context<'a> {
     pub fn set_fullscreen(&self, fullscreen: bool) {
+        let Some(window) = self.0.window() else {
+            return;
+        };
         match fullscreen {
-            true => self
-                .0
-                .window()
-                .set_fullscreen(Some(Fullscreen::Borderless(None))),
-            false => self.0.window.set_fullscreen(None),
+            true => window.set_fullscreen(Some(Fullscreen::Borderless(None))),
+            false => window.set_fullscreen(None),
         }
     }
 
     pub fn toggle_fullscreen(&self) {
-        match self.0.window().fullscreen() {
-            Some(_) => self.0.window().set_fullscreen(None),
-            None => self
-                .0
-                .window()
-                .set_fullscreen(Some(Fullscreen::Borderless(None))),
+        let Some(window) = self.0.window() else {
+            return;
+        };
+        match window.fullscreen() {
+            Some(_) => window.set_fullscreen(None),
+            None => window.set_fullscreen(Some(Fullscreen::Borderless(None))),
+        }
+    }
+
+    /// moves keyboard focus to the next focusable component, like pressing Tab
+    pub fn focus_next(&mut self) {
+        self.0.focus_next(false);
+    }
+
+    /// moves keyboard focus to the previous focusable component, like pressing Shift+Tab
+    pub fn focus_previous(&mut self) {
+        self.0.focus_next(true);
+    }
+
+    /// removes keyboard focus from the focused component
+    pub fn blur(&mut self) {
+        self.0.focus(None);
+    }
+
+    /// id of the component whose handler is running
+    pub fn current_id(&self) -> Option<isize> {
+        self.0.current
+    }
+
+    /// how far the [`crate::component::ScrollView`] with `id` is scrolled in physical pixels,
+    /// `None` if it is not a scroll view
+    pub fn scroll_offset(&self, id: isize) -> Option<(f32, f32)> {
+        self.0.scroll_offset(id)
+    }
+
+    /// scrolls the [`crate::component::ScrollView`] with `id` to `offset` in physical pixels,
+    /// clamped to its content
+    pub fn scroll_to(&mut self, id: isize, offset: (f32, f32)) {
+        self.0.update_scroll_content();
+        self.0.scroll_to(id, offset);
+    }
+
+    /// scrolls the [`crate::component::ScrollView`] with `id` by `delta` physical pixels
+    pub fn scroll_by(&mut self, id: isize, delta: (f32, f32)) {
+        self.0.update_scroll_content();
+        self.0.scroll_by(id, delta);
+    }
+
+    /// how far the world of the [`crate::component::Camera`] with `id` is panned on screen in
+    /// physical pixels, `None` if it is not a camera
+    pub fn camera_pan(&self, id: isize) -> Option<(f32, f32)> {
+        Some(self.0.camera_view(id)?.0)
+    }
+
+    pub fn camera_zoom(&self, id: isize) -> Option<f32> {
+        Some(self.0.camera_view(id)?.1)
+    }
+
+    pub fn camera_rotation(&self, id: isize) -> Option<f32> {
+        Some(self.0.camera_view(id)?.2)
+    }
+
+    /// moves the world of the [`crate::component::Camera`] with `id` by `delta` physical pixels
+    /// on screen
+    pub fn pan_camera(&mut self, id: isize, delta: (f32, f32)) {
+        self.0.pan_camera(id, delta);
+    }
+
+    /// zooms the [`crate::component::Camera`] with `id` to `zoom`, keeping the world under
+    /// `anchor` in place, `anchor` in physical pixels from the top left corner of the window
+    pub fn zoom_camera(&mut self, id: isize, zoom: f32, anchor: (f32, f32)) {
+        let Some((_, _, rotation)) = self.0.camera_view(id) else {
+            return;
+        };
+        self.0.set_camera_view(id, zoom, rotation, anchor);
+    }
+
+    /// rotates the world of the [`crate::component::Camera`] with `id` clockwise to `radians`
+    /// around the center of the camera
+    pub fn rotate_camera(&mut self, id: isize, radians: f32) {
+        let Some((_, zoom, _)) = self.0.camera_view(id) else {
+            return;
+        };
+        let (left, top, width, height) = self.0.bounds(id);
+        let center = self
+            .0
+            .world_transform(id)
+            .apply((left + width / 2., top + height / 2.));
+        self.0.set_camera_view(id, zoom, radians, center);
+    }
+
+    /// the world position of the [`crate::component::Camera`] with `id` shown at `point`, in
+    /// physical pixels from the top left corner of the window, world positions are physical
+    /// pixels from the top left corner of the camera as its children are laid out
+    pub fn screen_to_world(&self, id: isize, point: (f32, f32)) -> Option<(f32, f32)> {
+        self.0.screen_to_world(id, point)
+    }
+
+    /// where the world position `point` of the [`crate::component::Camera`] with `id` is shown,
+    /// in physical pixels from the top left corner of the window
+    pub fn world_to_screen(&self, id: isize, point: (f32, f32)) -> Option<(f32, f32)> {
+        self.0.world_to_screen(id, point)
+    }
+
+    /// moves the component `id` and its children by `offset` physical pixels, y pointing down
+    pub fn translate(&mut self, id: isize, offset: (f32, f32)) {
+        if self.0.components.get(id as usize).is_none() {
+            return;
         }
+        let delta = self.0.pixels_to_ndc(offset);
+        self.0.translate(id, delta);
+        self.0.request_redraw();
+    }
+
+    /// starts animating the component `id`, the animation stops by itself unless it repeats
+    /// forever
+    pub fn animate(&mut self, id: isize, animation: impl Into<Animation>) -> AnimationId {
+        self.0.animate(id, animation.into())
+    }
+
+    /// stops the animation, leaving the properties it animated where they are, returns false if
+    /// it already finished
+    pub fn cancel_animation(&mut self, animation: AnimationId) -> bool {
+        self.0.cancel_animation(animation)
+    }
+
+    pub fn is_animating(&self, animation: AnimationId) -> bool {
+        self.0.is_animating(animation)
+    }
+
+    /// pulls a property of the component `id` towards the target of `spring`, retargeting the
+    /// spring already moving that property without a jump
+    pub fn spring(&mut self, id: isize, spring: Spring) {
+        self.0.spring(id, spring);
+    }
+
+    /// stops the spring moving `property` of the component `id` where it is, returns false if
+    /// there is none
+    pub fn stop_spring(&mut self, id: isize, property: Property) -> bool {
+        self.0.stop_spring(id, property)
+    }
+
+    /// moves the top left corner of the component `id` and its children to `x`, `y` in fractions
+    /// of the window, see [`crate::component::Component::transition`]
+    pub fn set_position(&mut self, id: isize, x: f32, y: f32) {
+        self.0.change(id, Property::Position, window_position(x, y));
+    }
+
+    /// resizes the component `id` to `width`, `height` in fractions of the window
+    pub fn set_size(&mut self, id: isize, width: f32, height: f32) {
+        self.0
+            .change(id, Property::Size, window_size(width, height));
+    }
+
+    pub fn set_color(&mut self, id: isize, r: f32, g: f32, b: f32, a: f32) {
+        self.0.change(id, Property::Color, [r, g, b, a]);
+    }
+
+    pub fn set_radius(&mut self, id: isize, radius: f32) {
+        self.0.change(id, Property::Radius, [radius; 4]);
+    }
+
+    pub fn set_opacity(&mut self, id: isize, opacity: f32) {
+        self.0.change(id, Property::Opacity, [opacity, 0., 0., 0.]);
+    }
+
+    /// replaces the whole view before the next frame. Components at the same place in the new
+    /// tree and of the same kind take over from the old ones, keeping focus and animations,
+    /// and play their transitions from the values shown before, see
+    /// [`crate::component::Component::transition`].
+    pub fn set_view(&mut self, view: impl IntoComponent) {
+        self.0.pending_view = Some(view.into_comp());
+        self.0.request_redraw();
+    }
+
+    /// replaces the transform of the component `id`, see [`crate::component::Transform`]
+    pub fn set_transform(&mut self, id: isize, transform: Transform) {
+        let Some(comp) = self.0.components.get_mut(id as usize) else {
+            return;
+        };
+        comp.set_transform(transform);
+        self.0.request_redraw();
+    }
+
+    /// replaces the image shown by the [`crate::component::Img`] with `id` by the file at `path`
+    pub fn set_image(&mut self, id: isize, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let bytes = std::fs::read(path)?;
+        match self.0.components.get_mut(id as usize) {
+            Some(Comp::Img(img)) => img.set_content(&bytes)?,
+            _ => anyhow::bail!("component {id} is not an image"),
+        }
+        self.0.request_redraw();
+        Ok(())
+    }
+
+    /// thresholds for double clicks and long presses, see [`crate::Application::pointer_config`]
+    pub fn pointer_config(&mut self) -> &mut PointerConfig {
+        &mut self.0.pointer_config
+    }
+
+    /// feeds a synthetic touch into the input pipeline as if it came from the window, with
+    /// `position` in physical pixels
+    pub fn inject_touch(&mut self, finger: u64, phase: TouchPhase, position: (f32, f32)) {
+        self.0.touch(finger, phase, position);
+    }
+
+    /// application wide keyboard shortcuts, see [`Shortcuts`]
+    pub fn shortcuts(&mut self) -> &mut Shortcuts {
+        &mut self.0.shortcuts
+    }
+
+    /// closes the window and stops the event loop once the current event is handled, without
+    /// asking [`crate::Application::on_close_requested`]
+    pub fn exit(&mut self) {
+        self.0.exit_requested = true;
+    }
+
+    /// the current time according to [`crate::Application::clock`]
+    pub fn now(&self) -> Instant {
+        self.0.now()
+    }
+
+    pub fn clipboard(&mut self) -> &mut dyn Clipboard {
+        self.0.clipboard.as_mut()
+    }
+
+    /// copies the selection of the focused component to the clipboard like Ctrl+C, returns false
+    /// if nothing is selected
+    pub fn copy(&mut self) -> bool {
+        self.0.copy()
+    }
+
+    /// moves the selection of the focused component to the clipboard like Ctrl+X, returns false
+    /// if nothing is selected
+    pub fn cut(&mut self) -> bool {
+        let cut = self.0.cut();
+        self.0.update_ime_cursor_area();
+        self.0.request_redraw();
+        cut
+    }
+
+    /// inserts the clipboard into the focused component like Ctrl+V, returns false if it did not
+    /// take it
+    pub fn paste(&mut self) -> bool {
+        let pasted = self.0.paste();
+        self.0.update_ime_cursor_area();
+        self.0.request_redraw();
+        pasted
     }
 }
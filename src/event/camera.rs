@@ -0,0 +1,118 @@
+use winit::event::MouseScrollDelta;
+
+use crate::{
+    component::{Camera, Comp},
+    state::State,
+};
+
+use super::scroll_view::LINE_HEIGHT;
+
+/// how much one line of the mouse wheel zooms in or out
+const ZOOM_STEP: f32 = 1.1;
+
+impl State {
+    fn camera(&self, id: isize) -> Option<&Camera> {
+        match self.components.get(id as usize)? {
+            Comp::Camera(camera) => Some(camera),
+            _ => None,
+        }
+    }
+
+    fn camera_mut(&mut self, id: isize) -> Option<&mut Camera> {
+        match self.components.get_mut(id as usize)? {
+            Comp::Camera(camera) => Some(camera),
+            _ => None,
+        }
+    }
+
+    /// pan in physical pixels, zoom and rotation of the camera `id`
+    pub(crate) fn camera_view(&self, id: isize) -> Option<((f32, f32), f32, f32)> {
+        let camera = self.camera(id)?;
+        Some((
+            camera.pan_offset(),
+            camera.get_zoom(),
+            camera.get_rotation(),
+        ))
+    }
+
+    /// moves the world of the camera `id` by `delta` physical pixels on screen
+    pub(crate) fn pan_camera(&mut self, id: isize, delta: (f32, f32)) -> bool {
+        let Some(camera) = self.camera_mut(id) else {
+            return false;
+        };
+        let pan = camera.pan_offset();
+        camera.set_pan((pan.0 + delta.0, pan.1 + delta.1));
+        self.request_redraw();
+        true
+    }
+
+    /// sets zoom and rotation of the camera `id`, keeping the world under `anchor` in place,
+    /// `anchor` in physical pixels from the top left corner of the window
+    pub(crate) fn set_camera_view(
+        &mut self,
+        id: isize,
+        zoom: f32,
+        rotation: f32,
+        anchor: (f32, f32),
+    ) -> bool {
+        if self.camera(id).is_none() {
+            return false;
+        }
+        let Some(inverse) = self.world_transform(id).inverse() else {
+            return false;
+        };
+        let anchor = inverse.apply(anchor);
+        let bounds = self.bounds(id);
+        self.camera_mut(id)
+            .unwrap()
+            .set_view_around(zoom, rotation, anchor, bounds);
+        self.request_redraw();
+        true
+    }
+
+    /// the world position shown at `point`, both in physical pixels, the world measured from
+    /// the top left corner of the camera `id` before its view is applied
+    pub(crate) fn screen_to_world(&self, id: isize, point: (f32, f32)) -> Option<(f32, f32)> {
+        self.camera(id)?;
+        let (x, y) = self.content_transform(id).inverse()?.apply(point);
+        let (left, top, _, _) = self.bounds(id);
+        Some((x - left, y - top))
+    }
+
+    /// where the world position `point` of the camera `id` is shown, in physical pixels from the
+    /// top left corner of the window
+    pub(crate) fn world_to_screen(&self, id: isize, point: (f32, f32)) -> Option<(f32, f32)> {
+        self.camera(id)?;
+        let (left, top, _, _) = self.bounds(id);
+        Some(
+            self.content_transform(id)
+                .apply((point.0 + left, point.1 + top)),
+        )
+    }
+
+    /// zooms the nearest camera around `target` at the cursor, unless a scroll view or a text
+    /// area is closer and scrolls instead, returns false if nothing zoomed
+    pub(crate) fn wheel_zoom(&mut self, target: isize, delta: MouseScrollDelta) -> bool {
+        let lines = match delta {
+            MouseScrollDelta::LineDelta(_, y) => y,
+            MouseScrollDelta::PixelDelta(delta) => delta.y as f32 / LINE_HEIGHT,
+        };
+        let Some(id) = self.path(target).into_iter().find(|id| {
+            matches!(
+                self.components[*id as usize],
+                Comp::Camera(_) | Comp::ScrollView(_) | Comp::TextArea(_)
+            )
+        }) else {
+            return false;
+        };
+        let Some(camera) = self.camera(id).filter(|camera| camera.wheel_zooms()) else {
+            return false;
+        };
+        if lines == 0. {
+            return true;
+        }
+        let zoom = camera.get_zoom() * ZOOM_STEP.powf(lines);
+        let rotation = camera.get_rotation();
+        self.set_camera_view(id, zoom, rotation, self.cursor_pixels())
+    }
+}
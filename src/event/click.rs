@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use winit::event::MouseButton;
+
+use crate::{component::Component, state::State};
+
+use super::PointerKind;
+
+/// Thresholds for turning presses into click counts and long presses.
+#[derive(Debug, Clone, Copy)]
+pub struct PointerConfig {
+    /// longest time between two presses that still continue a multi-click
+    pub multi_click_time: Duration,
+    /// furthest distance in physical pixels between two presses that still continue a
+    /// multi-click
+    pub multi_click_distance: f32,
+    /// how long the left button has to be held to produce a long press
+    pub long_press_time: Duration,
+    /// how far in physical pixels the pointer may move before a held press stops counting as
+    /// a long press
+    pub long_press_distance: f32,
+}
+
+impl Default for PointerConfig {
+    fn default() -> Self {
+        Self {
+            multi_click_time: Duration::from_millis(500),
+            multi_click_distance: 4.,
+            long_press_time: Duration::from_millis(500),
+            long_press_distance: 8.,
+        }
+    }
+}
+
+/// A left press waiting to become a long press.
+struct LongPress {
+    target: isize,
+    position: (f32, f32),
+    deadline: Instant,
+}
+
+/// Tracks consecutive presses and the pending long press.
+#[derive(Default)]
+pub(crate) struct Clicks {
+    last: Option<(MouseButton, Instant, (f32, f32))>,
+    /// number of presses in quick succession, including the latest
+    pub count: u32,
+    long_press: Option<LongPress>,
+}
+
+impl State {
+    /// counts a press of `button`, continuing the current multi-click if it is close enough in
+    /// time and space
+    pub(crate) fn count_click(&mut self, button: MouseButton) -> u32 {
+        let now = self.now();
+        let position = self.cursor_pixels();
+        let config = self.pointer_config;
+        let continued = self.clicks.last.is_some_and(|(last, time, last_position)| {
+            last == button
+                && now - time <= config.multi_click_time
+                && (position.0 - last_position.0).hypot(position.1 - last_position.1)
+                    <= config.multi_click_distance
+        });
+        self.clicks.count = match continued {
+            true => self.clicks.count + 1,
+            false => 1,
+        };
+        self.clicks.last = Some((button, now, position));
+        self.clicks.count
+    }
+
+    /// starts timing a long press on `target` if it or one of its ancestors handles long
+    /// presses, so presses elsewhere keep their clicks and text selection
+    pub(crate) fn arm_long_press(&mut self, target: isize) {
+        let wanted = self.path(target).iter().any(|id| {
+            self.components[*id as usize]
+                .handlers()
+                .long_press
+                .is_some()
+        });
+        if !wanted {
+            self.cancel_long_press();
+            return;
+        }
+        self.clicks.long_press = Some(LongPress {
+            target,
+            position: self.cursor_pixels(),
+            deadline: self.now() + self.pointer_config.long_press_time,
+        });
+    }
+
+    pub(crate) fn cancel_long_press(&mut self) {
+        self.clicks.long_press = None;
+    }
+
+    /// gives up the pending long press once the pointer strays too far from the press
+    pub(crate) fn long_press_moved(&mut self) {
+        let position = self.cursor_pixels();
+        let limit = self.pointer_config.long_press_distance;
+        if let Some(press) = &self.clicks.long_press {
+            if (position.0 - press.position.0).hypot(position.1 - press.position.1) > limit {
+                self.cancel_long_press();
+            }
+        }
+    }
+
+    /// how long until [`State::update_timers`] next has something to do, by the clock of the
+    /// application
+    pub fn next_timeout(&self) -> Option<Duration> {
+        self.clicks
+            .long_press
+            .as_ref()
+            .map(|press| press.deadline.saturating_duration_since(self.now()))
+    }
+
+    /// fires the timers that have expired
+    pub fn update_timers(&mut self) {
+        let now = self.now();
+        let Some(press) = self
+            .clicks
+            .long_press
+            .take_if(|press| press.deadline <= now)
+        else {
+            return;
+        };
+        // a long press is not followed by a click
+        self.pressed.remove(&MouseButton::Left);
+        self.dispatch_pointer(
+            press.target,
+            PointerKind::LongPress,
+            Some(MouseButton::Left),
+        );
+    }
+}
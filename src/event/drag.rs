@@ -0,0 +1,191 @@
+use winit::{event::MouseButton, keyboard::ModifiersState};
+
+use crate::{
+    animation::{Property, Spring},
+    component::Component,
+    state::State,
+};
+
+use super::Phase;
+
+/// distance in physical pixels the pointer has to travel before a press turns into a drag
+const DRAG_THRESHOLD: f32 = 4.;
+
+/// Which step of a drag a [`DragEvent`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragKind {
+    Start,
+    Move,
+    Drop,
+}
+
+/// A draggable component being picked up, moved or dropped.
+///
+/// Start and move events go to the dragged component and bubble through its ancestors, drop
+/// events go to the nearest component under the pointer with an `on_drop` handler.
+#[derive(Debug, Clone)]
+pub struct DragEvent {
+    pub kind: DragKind,
+    /// id of the dragged component
+    pub source: isize,
+    /// id of the drop target under the pointer, if any
+    pub target: Option<isize>,
+    /// distance in physical pixels the pointer has moved since the drag started
+    pub offset: (f32, f32),
+    /// cursor position in physical pixels from the top left corner of the window
+    pub position: (f32, f32),
+    /// cursor position in physical pixels from the top left corner of the component handling
+    /// the event
+    pub local: (f32, f32),
+    pub modifiers: ModifiersState,
+    pub phase: Phase,
+    propagation_stopped: bool,
+    default_prevented: bool,
+}
+
+/// A press on a draggable component, which becomes a drag once the pointer moves far enough.
+pub(crate) struct Drag {
+    pub source: isize,
+    /// cursor position in physical pixels when the button was pressed
+    pub start: (f32, f32),
+    pub offset: (f32, f32),
+    pub started: bool,
+    pub target: Option<isize>,
+}
+
+impl State {
+    /// the component being dragged, once the drag has started
+    pub(crate) fn dragged(&self) -> Option<isize> {
+        self.drag
+            .as_ref()
+            .filter(|drag| drag.started)
+            .map(|drag| drag.source)
+    }
+
+    /// remembers a press on `target` in case it turns into a drag
+    pub(crate) fn drag_press(&mut self, target: isize) {
+        let source = self
+            .path(target)
+            .into_iter()
+            .find(|id| self.components[*id as usize].is_draggable());
+        self.drag = source.map(|source| Drag {
+            source,
+            start: self.cursor_pixels(),
+            offset: (0., 0.),
+            started: false,
+            target: None,
+        });
+        if let Some(source) = source {
+            // catching a component that is still springing back
+            self.stop_spring(source, Property::Position);
+        }
+    }
+
+    fn drag_event(&self, drag: &Drag, kind: DragKind) -> DragEvent {
+        DragEvent {
+            kind,
+            source: drag.source,
+            target: drag.target,
+            offset: drag.offset,
+            position: self.cursor_pixels(),
+            local: (0., 0.),
+            modifiers: self.modifiers,
+            phase: Phase::Target,
+            propagation_stopped: false,
+            default_prevented: false,
+        }
+    }
+
+    /// the nearest component under the pointer, outside of the dragged one, that accepts drops
+    fn drop_target(&self) -> Option<isize> {
+        let source = self.drag.as_ref()?.source;
+        let id = self.hit_test(self.cursor_pixels(), Some(source))?;
+        self.path(id)
+            .into_iter()
+            .find(|id| self.components[*id as usize].handlers().drop.is_some())
+    }
+
+    /// starts the drag once the pointer is far enough from the press, then moves the dragged
+    /// component along with it
+    pub(crate) fn drag_move(&mut self) {
+        let position = self.cursor_pixels();
+        let Some(drag) = self.drag.as_mut() else {
+            return;
+        };
+        let offset = (position.0 - drag.start.0, position.1 - drag.start.1);
+        let source = drag.source;
+        if !drag.started {
+            if offset.0.hypot(offset.1) < DRAG_THRESHOLD {
+                return;
+            }
+            drag.started = true;
+            // a drag is never a click or long press, and no longer selects text
+            self.pressed.remove(&MouseButton::Left);
+            self.cancel_long_press();
+            self.captured = Some(source);
+            let mut event = self.drag_event(self.drag.as_ref().unwrap(), DragKind::Start);
+            self.dispatch(source, &mut event, |h| &h.drag_start, |_| &None);
+            if event.default_prevented() {
+                self.drag = None;
+                self.captured = None;
+                return;
+            }
+        }
+        let drag = self.drag.as_mut().unwrap();
+        let delta = (offset.0 - drag.offset.0, offset.1 - drag.offset.1);
+        drag.offset = offset;
+        let delta = self.layout_delta(source, delta);
+        self.translate(source, self.pixels_to_ndc(delta));
+        let target = self.drop_target();
+        self.drag.as_mut().unwrap().target = target;
+        let mut event = self.drag_event(self.drag.as_ref().unwrap(), DragKind::Move);
+        self.dispatch(source, &mut event, |h| &h.drag, |_| &None);
+        self.request_redraw();
+    }
+
+    /// ends the drag on release, returns true if a drag was in progress
+    pub(crate) fn drag_release(&mut self) -> bool {
+        let Some(drag) = self.drag.take_if(|drag| drag.started) else {
+            self.drag = None;
+            return false;
+        };
+        self.captured = None;
+        // the dragged component returns, `on_drop` decides where it ends up
+        let offset = self.layout_delta(drag.source, (-drag.offset.0, -drag.offset.1));
+        let offset = self.pixels_to_ndc(offset);
+        let Some(target) = drag.target else {
+            // nothing to drop on, spring back to where it was picked up
+            let (x, y) = self.components[drag.source as usize].get_position();
+            let home = [x + offset.0, y + offset.1, 0., 0.];
+            self.spring(drag.source, Spring::new(Property::Position, home));
+            return true;
+        };
+        self.translate(drag.source, offset);
+        let mut event = self.drag_event(&drag, DragKind::Drop);
+        self.dispatch(target, &mut event, |h| &h.drop, |_| &None);
+        self.request_redraw();
+        true
+    }
+
+    /// converts a distance moved on screen in physical pixels into the space `id` is laid out
+    /// in, so components inside a zoomed or rotated camera keep up with the pointer
+    fn layout_delta(&self, id: isize, delta: (f32, f32)) -> (f32, f32) {
+        let Some(inverse) =
+            self.parents[id as usize].and_then(|parent| self.content_transform(parent).inverse())
+        else {
+            return delta;
+        };
+        let (origin, moved) = (inverse.apply((0., 0.)), inverse.apply(delta));
+        (moved.0 - origin.0, moved.1 - origin.1)
+    }
+
+    /// converts a distance in physical pixels, y pointing down, to screen space
+    pub(crate) fn pixels_to_ndc(&self, delta: (f32, f32)) -> (f32, f32) {
+        (
+            delta.0 / self.config.width as f32 * 2.,
+            -delta.1 / self.config.height as f32 * 2.,
+        )
+    }
+}
+
+impl_propagation!(DragEvent, local);
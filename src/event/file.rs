@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use crate::{component::Component, state::State};
+
+/// Files dragged in from outside the window and dropped on a component.
+#[derive(Debug, Clone)]
+pub struct FileDropEvent {
+    pub paths: Vec<PathBuf>,
+    /// id of the component the files were dropped on
+    pub target: isize,
+    /// cursor position in physical pixels from the top left corner of the window
+    pub position: (f32, f32),
+    /// cursor position in physical pixels from the top left corner of the target
+    pub local: (f32, f32),
+}
+
+/// Files hovering over or dropped on the window.
+///
+/// winit reports each file of a drop as its own event, they are collected here and delivered
+/// together once the event loop runs out of events.
+#[derive(Default)]
+pub(crate) struct FileDrop {
+    pub hovered: Vec<PathBuf>,
+    pub dropped: Vec<PathBuf>,
+    /// the component highlighted under hovering files
+    pub target: Option<isize>,
+    /// where the files hover in physical pixels, the drop lands there
+    pub position: Option<(f32, f32)>,
+}
+
+impl State {
+    /// the nearest component at `position` in physical pixels that accepts dropped files
+    fn file_drop_target(&self, position: (f32, f32)) -> Option<isize> {
+        let id = self.hit_test(position, self.dragged())?;
+        self.path(id)
+            .into_iter()
+            .find(|id| self.components[*id as usize].handlers().file_drop.is_some())
+    }
+
+    /// a file hovering at `position` in physical pixels
+    ///
+    /// Some platforms stop reporting cursor moves while the system drags files, so each hovered
+    /// file moves the highlight and the drop to where it was reported.
+    pub(crate) fn file_hovered(&mut self, path: PathBuf, position: (f32, f32)) {
+        self.file_drop.hovered.push(path);
+        self.file_hover_moved(position);
+    }
+
+    /// follows `position` in physical pixels with the highlight while files are hovering over
+    /// the window
+    pub(crate) fn file_hover_moved(&mut self, position: (f32, f32)) {
+        if self.file_drop.hovered.is_empty() {
+            return;
+        }
+        self.file_drop.position = Some(position);
+        let target = self.file_drop_target(position);
+        if self.file_drop.target != target {
+            self.file_drop.target = target;
+            self.request_redraw();
+        }
+    }
+
+    pub(crate) fn file_hover_cancelled(&mut self) {
+        self.file_drop.hovered.clear();
+        self.file_drop.target = None;
+        self.file_drop.position = None;
+        self.request_redraw();
+    }
+
+    pub(crate) fn file_dropped(&mut self, path: PathBuf) {
+        self.file_drop.dropped.push(path);
+    }
+
+    /// delivers the files dropped since the last call to the component they were hovering
+    /// over, or to the one under the pointer
+    pub fn flush_file_drop(&mut self) {
+        if self.file_drop.dropped.is_empty() {
+            return;
+        }
+        let paths = std::mem::take(&mut self.file_drop.dropped);
+        let position = self.file_drop.position.unwrap_or(self.cursor_pixels());
+        self.file_hover_cancelled();
+        let Some(target) = self.file_drop_target(position) else {
+            return;
+        };
+        let mut event = FileDropEvent {
+            paths,
+            target,
+            position,
+            local: self.local_pixels_at(target, position),
+        };
+        self.call(target, |h| &h.file_drop, &mut event);
+    }
+}
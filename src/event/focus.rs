@@ -0,0 +1,76 @@
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+
+use crate::{
+    component::{Comp, Component},
+    state::State,
+};
+
+impl State {
+    pub(crate) fn focused_mut(&mut self) -> Option<&mut Comp> {
+        let id = self.focused?;
+        self.components.get_mut(id as usize)
+    }
+
+    /// Moves keyboard focus to the component with `id`, toggling the input method to match it.
+    pub fn focus(&mut self, id: Option<isize>) {
+        if self.focused == id {
+            return;
+        }
+        if let Some(comp) = self.focused_mut() {
+            comp.set_focus(false);
+        }
+        self.focused = id;
+        let ime_allowed = match self.focused_mut() {
+            Some(comp) => {
+                comp.set_focus(true);
+                comp.ime_allowed()
+            }
+            None => false,
+        };
+        if let Some(window) = self.window() {
+            window.set_ime_allowed(ime_allowed);
+        }
+        self.update_ime_cursor_area();
+        self.request_redraw();
+    }
+
+    /// Moves keyboard focus to the next focusable component in tree order, or the previous one
+    /// if `reverse`, wrapping around at the ends.
+    pub fn focus_next(&mut self, reverse: bool) {
+        let mut candidates: Vec<isize> = self
+            .tree_order
+            .iter()
+            .copied()
+            .filter(|id| self.components[*id as usize].is_focusable() && self.shown(*id))
+            .collect();
+        if reverse {
+            candidates.reverse();
+        }
+        let next = match self
+            .focused
+            .and_then(|id| candidates.iter().position(|candidate| *candidate == id))
+        {
+            Some(index) => candidates.get(index + 1).or(candidates.first()),
+            None => candidates.first(),
+        };
+        let next = next.copied();
+        self.focus_visible = next.is_some();
+        self.focus(next);
+        self.request_redraw();
+    }
+
+    pub(crate) fn update_ime_cursor_area(&self) {
+        let Some(comp) = self.focused.and_then(|id| self.components.get(id as usize)) else {
+            return;
+        };
+        let Some(window) = self.window() else {
+            return;
+        };
+        if let Some(((x, y), (width, height))) = comp.ime_cursor_area(&self.config) {
+            window.set_ime_cursor_area(
+                PhysicalPosition::new(x, y),
+                PhysicalSize::new(width, height),
+            );
+        }
+    }
+}
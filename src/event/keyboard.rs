@@ -0,0 +1,151 @@
+use winit::{
+    event::{ElementState, KeyEvent},
+    keyboard::{Key, ModifiersState, NamedKey, NativeKeyCode, PhysicalKey},
+};
+
+use crate::{component::Component, state::State};
+
+use super::{Phase, Select};
+
+/// A key press or release, delivered to the focused component and its ancestors, or to the
+/// root when nothing is focused.
+#[derive(Debug, Clone)]
+pub struct KeyboardEvent {
+    /// the key with the keyboard layout and modifiers applied
+    pub key: Key,
+    /// the key position on the keyboard, independent of the layout
+    pub physical_key: PhysicalKey,
+    /// the text produced by the key press, if any
+    pub text: Option<String>,
+    pub state: ElementState,
+    /// whether the key is held down and this event was generated by key repeat
+    pub repeat: bool,
+    pub modifiers: ModifiersState,
+    pub phase: Phase,
+    propagation_stopped: bool,
+    default_prevented: bool,
+}
+
+impl State {
+    pub(crate) fn keyboard_input(&mut self, event: &KeyEvent) -> bool {
+        self.key_input(KeyboardEvent {
+            key: event.logical_key.clone(),
+            physical_key: event.physical_key,
+            text: event.text.as_ref().map(|text| text.to_string()),
+            state: event.state,
+            repeat: event.repeat,
+            modifiers: self.modifiers,
+            phase: Phase::Target,
+            propagation_stopped: false,
+            default_prevented: false,
+        })
+    }
+
+    /// a key pressed or released without a window, see [`crate::Headless::key`]
+    pub(crate) fn inject_key(&mut self, key: Key, state: ElementState) {
+        let text = match (&key, state) {
+            (Key::Character(c), ElementState::Pressed) => Some(c.to_string()),
+            _ => None,
+        };
+        self.key_input(KeyboardEvent {
+            key,
+            physical_key: PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+            text,
+            state,
+            repeat: false,
+            modifiers: self.modifiers,
+            phase: Phase::Target,
+            propagation_stopped: false,
+            default_prevented: false,
+        });
+    }
+
+    fn key_input(&mut self, mut event: KeyboardEvent) -> bool {
+        if event.state == ElementState::Pressed {
+            if let Some(action) = self.shortcuts.find(&event.key, self.modifiers) {
+                // holding a shortcut down triggers it once
+                if !event.repeat {
+                    (action.borrow_mut())(self.as_ctx());
+                    self.request_redraw();
+                }
+                return true;
+            }
+        }
+        let Some(target) = self.focused.or(self.tree_order.first().copied()) else {
+            return false;
+        };
+        let select: Select<KeyboardEvent> = match event.state {
+            ElementState::Pressed => |h| &h.key_down,
+            ElementState::Released => |h| &h.key_up,
+        };
+        self.dispatch(target, &mut event, select, |h| &h.key_capture);
+        if event.default_prevented() {
+            return true;
+        }
+
+        if self.clipboard_input(&event)
+            || self
+                .focused_mut()
+                .is_some_and(|comp| comp.keyboard_input(&event))
+        {
+            self.update_ime_cursor_area();
+            self.request_redraw();
+            return true;
+        }
+        if event.state == ElementState::Pressed
+            && event.key == Key::Named(NamedKey::Tab)
+            && !self.modifiers.alt_key()
+        {
+            self.focus_next(self.modifiers.shift_key());
+            return true;
+        }
+        false
+    }
+
+    /// handles the copy, cut and paste shortcuts for the focused component
+    fn clipboard_input(&mut self, event: &KeyboardEvent) -> bool {
+        let Key::Character(c) = &event.key else {
+            return false;
+        };
+        let ctrl = self.modifiers.control_key() || self.modifiers.super_key();
+        if event.state != ElementState::Pressed || !ctrl {
+            return false;
+        }
+        match c.to_lowercase().as_str() {
+            "c" => self.copy(),
+            "x" => self.cut(),
+            "v" => self.paste(),
+            _ => false,
+        }
+    }
+
+    /// copies the selection of the focused component to the clipboard, returns false if
+    /// nothing is selected
+    pub(crate) fn copy(&mut self) -> bool {
+        let Some(content) = self.focused_mut().and_then(|comp| comp.copy()) else {
+            return false;
+        };
+        self.clipboard.set(content);
+        true
+    }
+
+    /// moves the selection of the focused component to the clipboard, returns false if nothing
+    /// is selected
+    pub(crate) fn cut(&mut self) -> bool {
+        let Some(content) = self.focused_mut().and_then(|comp| comp.cut()) else {
+            return false;
+        };
+        self.clipboard.set(content);
+        true
+    }
+
+    /// inserts the clipboard into the focused component, returns false if it did not take it
+    pub(crate) fn paste(&mut self) -> bool {
+        let Some(content) = self.clipboard.get() else {
+            return false;
+        };
+        self.focused_mut().is_some_and(|comp| comp.paste(&content))
+    }
+}
+
+impl_propagation!(KeyboardEvent);
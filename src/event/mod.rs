@@ -0,0 +1,220 @@
+macro_rules! impl_propagation {
+    (@methods $event:ty) => {
+        impl $event {
+            /// prevents the event from reaching any further component
+            pub fn stop_propagation(&mut self) {
+                self.propagation_stopped = true;
+            }
+
+            /// skips the built-in behavior that follows the event, such as focusing or text
+            /// selection on mouse down
+            pub fn prevent_default(&mut self) {
+                self.default_prevented = true;
+            }
+
+            pub fn default_prevented(&self) -> bool {
+                self.default_prevented
+            }
+        }
+    };
+    ($event:ty, local) => {
+        impl_propagation!(@methods $event);
+
+        impl $crate::event::Propagate for $event {
+            fn visit(&mut self, phase: $crate::event::Phase, local: (f32, f32)) {
+                self.phase = phase;
+                self.local = local;
+            }
+
+            fn stopped(&self) -> bool {
+                self.propagation_stopped
+            }
+        }
+    };
+    ($event:ty) => {
+        impl_propagation!(@methods $event);
+
+        impl $crate::event::Propagate for $event {
+            fn visit(&mut self, phase: $crate::event::Phase, _local: (f32, f32)) {
+                self.phase = phase;
+            }
+
+            fn stopped(&self) -> bool {
+                self.propagation_stopped
+            }
+        }
+    };
+}
+
+mod camera;
+mod click;
+mod drag;
+mod file;
+mod focus;
+mod keyboard;
+mod pointer;
+mod scroll_view;
+mod touch;
+
+pub(crate) use click::Clicks;
+pub use click::PointerConfig;
+pub(crate) use drag::Drag;
+pub use drag::{DragEvent, DragKind};
+pub(crate) use file::FileDrop;
+pub use file::FileDropEvent;
+pub use keyboard::KeyboardEvent;
+pub use pointer::{PointerEvent, PointerKind, ScrollEvent};
+pub(crate) use scroll_view::ScrollbarDrag;
+pub(crate) use touch::Touches;
+pub use touch::{GestureEvent, GestureKind, GestureState};
+use winit::{event::WindowEvent, keyboard::ModifiersState};
+
+use crate::{
+    component::{Comp, Component, Handler, Handlers},
+    state::State,
+};
+
+/// Where an event currently is on its way through the component hierarchy.
+///
+/// Events first travel from the root down to the target (capture), are then delivered to the
+/// target itself, and finally travel back up through its ancestors (bubble).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Capture,
+    Target,
+    Bubble,
+}
+
+pub(crate) trait Propagate {
+    fn visit(&mut self, phase: Phase, local: (f32, f32));
+
+    fn stopped(&self) -> bool;
+}
+
+type Select<E> = fn(&Handlers) -> &Option<Handler<E>>;
+
+impl State {
+    /// the topmost visible component whose shape is under the cursor and not clipped away by
+    /// one of its ancestors, looking through the component being dragged
+    fn get_element_by_pos(&self) -> Option<&Comp> {
+        let id = self.hit_test(self.cursor_pixels(), self.dragged())?;
+        Some(&self.components[id as usize])
+    }
+
+    /// the topmost visible component whose shape is at `point` in physical pixels and not
+    /// clipped away by one of its ancestors, looking through `excluded` and its descendants
+    pub(crate) fn hit_test(&self, point: (f32, f32), excluded: Option<isize>) -> Option<isize> {
+        (0..self.components.len() as isize)
+            .rev()
+            .find(|id| self.hits(*id, point, excluded))
+    }
+
+    /// whether `point` in physical pixels falls on the shape of the visible component `id` and
+    /// is not clipped away by one of its ancestors, never for `excluded` and its descendants
+    pub(crate) fn hits(&self, id: isize, point: (f32, f32), excluded: Option<isize>) -> bool {
+        self.components[id as usize].contains(self.point_in(id, point), &self.config)
+            && self.path(id).iter().all(|ancestor| {
+                let comp = &self.components[*ancestor as usize];
+                !comp.hidden()
+                    && Some(*ancestor) != excluded
+                    && (*ancestor == id
+                        || !comp.clips_children()
+                        || comp.contains(self.point_in(*ancestor, point), &self.config))
+            })
+    }
+
+    /// the component `id` followed by its ancestors up to the root
+    pub(crate) fn path(&self, id: isize) -> Vec<isize> {
+        let mut path = vec![id];
+        while let Some(parent) = self.parents[*path.last().unwrap() as usize] {
+            path.push(parent);
+        }
+        path
+    }
+
+    /// every descendant of the component `id`, in tree pre-order
+    pub(crate) fn descendants(&self, id: isize) -> &[isize] {
+        &self.tree_order[self.subtrees[id as usize].clone()]
+    }
+
+    /// calls the handler picked by `select` on the component `id`, returns false if it has none
+    fn call<E>(&mut self, id: isize, select: Select<E>, event: &mut E) -> bool {
+        let Some(handler) = select(self.components[id as usize].handlers()).clone() else {
+            return false;
+        };
+        let current = self.current.replace(id);
+        (handler.borrow_mut())(self.as_ctx(), event);
+        self.current = current;
+        self.request_redraw();
+        true
+    }
+
+    /// delivers `event` to the capture handlers from the root down to `target`, then to the
+    /// handlers picked by `select` from `target` back up to the root
+    fn dispatch<E: Propagate>(
+        &mut self,
+        target: isize,
+        event: &mut E,
+        select: Select<E>,
+        capture: Select<E>,
+    ) {
+        let path = self.path(target);
+        for &id in path.iter().rev() {
+            let phase = match id == target {
+                true => Phase::Target,
+                false => Phase::Capture,
+            };
+            event.visit(phase, self.local_pixels(id));
+            self.call(id, capture, event);
+            if event.stopped() {
+                return;
+            }
+        }
+        for &id in path.iter() {
+            let phase = match id == target {
+                true => Phase::Target,
+                false => Phase::Bubble,
+            };
+            event.visit(phase, self.local_pixels(id));
+            self.call(id, select, event);
+            if event.stopped() {
+                return;
+            }
+        }
+    }
+
+    pub fn input(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::MouseInput { state, button, .. } => self.mouse_input(*state, *button),
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_moved(*position);
+                self.file_hover_moved(self.cursor_pixels());
+            }
+            WindowEvent::CursorLeft { .. } => self.hover(None),
+            WindowEvent::MouseWheel { delta, .. } => self.mouse_wheel(*delta),
+            WindowEvent::ModifiersChanged(modifiers) => self.modifiers = modifiers.state(),
+            // modifiers released while another window has focus are never reported
+            WindowEvent::Focused(false) => self.modifiers = ModifiersState::empty(),
+            WindowEvent::KeyboardInput { event, .. } => return self.keyboard_input(event),
+            WindowEvent::Touch(touch) => self.touch(
+                touch.id,
+                touch.phase,
+                (touch.location.x as f32, touch.location.y as f32),
+            ),
+            WindowEvent::HoveredFile(path) => self.file_hovered(path.clone(), self.cursor_pixels()),
+            WindowEvent::HoveredFileCancelled => self.file_hover_cancelled(),
+            WindowEvent::DroppedFile(path) => self.file_dropped(path.clone()),
+            WindowEvent::Ime(ime) => {
+                if let Some(comp) = self.focused_mut() {
+                    if comp.ime(ime) {
+                        self.update_ime_cursor_area();
+                        self.request_redraw();
+                        return true;
+                    }
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+}
@@ -0,0 +1,313 @@
+use winit::{
+    dpi::PhysicalPosition,
+    event::{ElementState, MouseButton, MouseScrollDelta},
+    keyboard::ModifiersState,
+};
+
+use crate::{component::Component, state::State};
+
+use super::{Phase, Propagate, Select};
+
+/// Which pointer interaction a [`PointerEvent`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerKind {
+    Down,
+    Up,
+    Click,
+    RightClick,
+    MiddleClick,
+    /// the second click of a multi-click, following its [`PointerKind::Click`]
+    DoubleClick,
+    /// the left button held down without moving, see [`crate::PointerConfig`]
+    LongPress,
+    Move,
+    Enter,
+    Leave,
+}
+
+/// A mouse button or movement over a component.
+#[derive(Debug, Clone)]
+pub struct PointerEvent {
+    pub kind: PointerKind,
+    /// the button that changed, `None` for movement, enter and leave
+    pub button: Option<MouseButton>,
+    /// number of presses in quick succession, 0 for movement, enter and leave
+    pub click_count: u32,
+    /// cursor position in physical pixels from the top left corner of the window
+    pub position: (f32, f32),
+    /// cursor position in physical pixels from the top left corner of the component handling
+    /// the event
+    pub local: (f32, f32),
+    pub modifiers: ModifiersState,
+    pub phase: Phase,
+    propagation_stopped: bool,
+    default_prevented: bool,
+}
+
+/// A mouse wheel or touchpad scroll over a component.
+#[derive(Debug, Clone)]
+pub struct ScrollEvent {
+    pub delta: MouseScrollDelta,
+    /// cursor position in physical pixels from the top left corner of the window
+    pub position: (f32, f32),
+    /// cursor position in physical pixels from the top left corner of the component handling
+    /// the event
+    pub local: (f32, f32),
+    pub modifiers: ModifiersState,
+    pub phase: Phase,
+    propagation_stopped: bool,
+    default_prevented: bool,
+}
+
+impl State {
+    /// cursor position in physical pixels from the top left corner of the window
+    pub(crate) fn cursor_pixels(&self) -> (f32, f32) {
+        (
+            ((self.cursor_pos.0 + 1.) / 2. * self.config.width as f64) as f32,
+            ((1. - self.cursor_pos.1) / 2. * self.config.height as f64) as f32,
+        )
+    }
+
+    /// cursor position in physical pixels with the transforms of `id` and its ancestors undone,
+    /// in the space the component is laid out in
+    pub(crate) fn cursor_pixels_in(&self, id: isize) -> (f32, f32) {
+        self.pixels_in(id, self.cursor_pixels())
+    }
+
+    /// `point` in physical pixels with the transforms of `id` and its ancestors undone
+    fn pixels_in(&self, id: isize, point: (f32, f32)) -> (f32, f32) {
+        match self.world_transform(id).inverse() {
+            Some(inverse) => inverse.apply(point),
+            // flattened components are never under the cursor
+            None => (f32::INFINITY, f32::INFINITY),
+        }
+    }
+
+    /// cursor position in screen space with the transforms of `id` and its ancestors undone
+    pub(crate) fn cursor_in(&self, id: isize) -> (f32, f32) {
+        self.point_in(id, self.cursor_pixels())
+    }
+
+    /// `point` in physical pixels converted to screen space with the transforms of `id` and its
+    /// ancestors undone
+    pub(crate) fn point_in(&self, id: isize, point: (f32, f32)) -> (f32, f32) {
+        let (x, y) = self.pixels_in(id, point);
+        (
+            x / self.config.width as f32 * 2. - 1.,
+            1. - y / self.config.height as f32 * 2.,
+        )
+    }
+
+    /// cursor position in physical pixels from the top left corner of the component `id`
+    pub(crate) fn local_pixels(&self, id: isize) -> (f32, f32) {
+        self.local_pixels_at(id, self.cursor_pixels())
+    }
+
+    /// `point` in physical pixels from the top left corner of the component `id`
+    pub(crate) fn local_pixels_at(&self, id: isize, point: (f32, f32)) -> (f32, f32) {
+        let (x, y) = self.pixels_in(id, point);
+        let (left, top, _, _) = self.bounds(id);
+        (x - left, y - top)
+    }
+
+    fn pointer_event(&self, kind: PointerKind, button: Option<MouseButton>) -> PointerEvent {
+        PointerEvent {
+            kind,
+            button,
+            click_count: match button {
+                Some(_) => self.clicks.count,
+                None => 0,
+            },
+            position: self.cursor_pixels(),
+            local: (0., 0.),
+            modifiers: self.modifiers,
+            phase: Phase::Target,
+            propagation_stopped: false,
+            default_prevented: false,
+        }
+    }
+
+    pub(crate) fn dispatch_pointer(
+        &mut self,
+        target: isize,
+        kind: PointerKind,
+        button: Option<MouseButton>,
+    ) -> PointerEvent {
+        let mut event = self.pointer_event(kind, button);
+        let select: Select<PointerEvent> = match kind {
+            PointerKind::Down => |h| &h.mouse_down,
+            PointerKind::Up => |h| &h.mouse_up,
+            PointerKind::Click => |h| &h.click,
+            PointerKind::RightClick => |h| &h.right_click,
+            PointerKind::MiddleClick => |h| &h.middle_click,
+            PointerKind::DoubleClick => |h| &h.double_click,
+            PointerKind::LongPress => |h| &h.long_press,
+            PointerKind::Move => |h| &h.mouse_move,
+            PointerKind::Enter => |h| &h.mouse_enter,
+            PointerKind::Leave => |h| &h.mouse_leave,
+        };
+        self.dispatch(target, &mut event, select, |h| &h.pointer_capture);
+        event
+    }
+
+    pub(crate) fn mouse_input(&mut self, state: ElementState, button: MouseButton) {
+        let target = self
+            .captured
+            .or_else(|| self.get_element_by_pos().map(|comp| comp.get_id()));
+        match state {
+            ElementState::Pressed => {
+                if button == MouseButton::Left && self.scrollbar_press() {
+                    return;
+                }
+                let click_count = self.count_click(button);
+                let default_prevented = match target {
+                    Some(id) => {
+                        self.pressed.insert(button, id);
+                        self.dispatch_pointer(id, PointerKind::Down, Some(button))
+                            .default_prevented()
+                    }
+                    None => {
+                        self.pressed.remove(&button);
+                        false
+                    }
+                };
+                if button != MouseButton::Left || default_prevented {
+                    return;
+                }
+                let focus = target.and_then(|id| {
+                    self.path(id)
+                        .into_iter()
+                        .find(|id| self.components[*id as usize].is_focusable())
+                });
+                self.focus_visible = false;
+                self.focus(focus);
+                if let Some(id) = target {
+                    self.drag_press(id);
+                    self.arm_long_press(id);
+                }
+                if let Some(id) = target {
+                    let cursor = self.cursor_in(id);
+                    let comp = &mut self.components[id as usize];
+                    if comp.mouse_down(cursor, click_count, &self.config) {
+                        self.update_ime_cursor_area();
+                        self.request_redraw();
+                    }
+                }
+            }
+            ElementState::Released => {
+                if self.scrollbar_release(button) {
+                    return;
+                }
+                if button == MouseButton::Left {
+                    self.cancel_long_press();
+                }
+                let pressed = self.pressed.remove(&button);
+                let Some(id) = target else {
+                    if button == MouseButton::Left {
+                        self.drag_release();
+                    }
+                    return;
+                };
+                self.dispatch_pointer(id, PointerKind::Up, Some(button));
+                if button == MouseButton::Left && self.drag_release() {
+                    return;
+                }
+                // like the DOM, the click goes to the nearest common ancestor of the components
+                // under press and release
+                let Some(pressed) = pressed else {
+                    return;
+                };
+                let released = self.path(id);
+                let Some(target) = self
+                    .path(pressed)
+                    .into_iter()
+                    .find(|ancestor| released.contains(ancestor))
+                else {
+                    return;
+                };
+                let kind = match button {
+                    MouseButton::Left => PointerKind::Click,
+                    MouseButton::Right => PointerKind::RightClick,
+                    MouseButton::Middle => PointerKind::MiddleClick,
+                    _ => return,
+                };
+                self.dispatch_pointer(target, kind, Some(button));
+                if kind == PointerKind::Click && self.clicks.count == 2 {
+                    self.dispatch_pointer(target, PointerKind::DoubleClick, Some(button));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn cursor_moved(&mut self, position: PhysicalPosition<f64>) {
+        let size = (self.size().width as f64, self.size().height as f64);
+        self.cursor_pos = (
+            position.x / size.0 * 2. - 1.,
+            -position.y / size.1 * 2. + 1.,
+        );
+        if self.scrollbar_move() {
+            return;
+        }
+        self.long_press_moved();
+        self.drag_move();
+        if let Some(&id) = self.pressed.get(&MouseButton::Left) {
+            let cursor = self.cursor_in(id);
+            if self.components[id as usize].mouse_drag(cursor, &self.config) {
+                self.update_ime_cursor_area();
+                self.request_redraw();
+            }
+        }
+        let target = self
+            .captured
+            .or_else(|| self.get_element_by_pos().map(|comp| comp.get_id()));
+        self.hover(target);
+        if let Some(id) = target {
+            self.dispatch_pointer(id, PointerKind::Move, None);
+        }
+    }
+
+    /// moves the hovered component to `target`
+    ///
+    /// Like the DOM, enter and leave do not bubble: every component that the pointer leaves or
+    /// enters, including ancestors of the previous and new target, receives its own event.
+    pub(crate) fn hover(&mut self, target: Option<isize>) {
+        if self.hovered == target {
+            return;
+        }
+        let old = self.hovered.map(|id| self.path(id)).unwrap_or_default();
+        let new = target.map(|id| self.path(id)).unwrap_or_default();
+        self.hovered = target;
+        for &id in old.iter().filter(|id| !new.contains(id)) {
+            let mut event = self.pointer_event(PointerKind::Leave, None);
+            event.visit(Phase::Target, self.local_pixels(id));
+            self.call(id, |h| &h.mouse_leave, &mut event);
+        }
+        for &id in new.iter().rev().filter(|id| !old.contains(id)) {
+            let mut event = self.pointer_event(PointerKind::Enter, None);
+            event.visit(Phase::Target, self.local_pixels(id));
+            self.call(id, |h| &h.mouse_enter, &mut event);
+        }
+    }
+
+    pub(crate) fn mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        let Some(id) = self.get_element_by_pos().map(|comp| comp.get_id()) else {
+            return;
+        };
+        let mut event = ScrollEvent {
+            delta,
+            position: self.cursor_pixels(),
+            local: (0., 0.),
+            modifiers: self.modifiers,
+            phase: Phase::Target,
+            propagation_stopped: false,
+            default_prevented: false,
+        };
+        self.dispatch(id, &mut event, |h| &h.scroll, |h| &h.scroll_capture);
+        if !event.default_prevented() && !self.wheel_zoom(id, delta) {
+            self.wheel_scroll(id, delta);
+        }
+    }
+}
+
+impl_propagation!(PointerEvent, local);
+impl_propagation!(ScrollEvent, local);
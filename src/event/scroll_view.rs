@@ -0,0 +1,194 @@
+use winit::event::{MouseButton, MouseScrollDelta};
+
+use crate::{
+    component::{Axis, Comp, Component, ScrollView},
+    state::State,
+};
+
+/// distance in physical pixels scrolled per line reported by the mouse wheel
+pub(super) const LINE_HEIGHT: f32 = 40.;
+
+/// A scrollbar thumb held down by the left button.
+pub(crate) struct ScrollbarDrag {
+    view: isize,
+    axis: Axis,
+    /// cursor position in physical pixels when the thumb was pressed
+    start: (f32, f32),
+    /// scroll offset when the thumb was pressed
+    offset: (f32, f32),
+}
+
+impl State {
+    fn scroll_view(&self, id: isize) -> Option<&ScrollView> {
+        match self.components.get(id as usize)? {
+            Comp::ScrollView(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    fn scroll_view_mut(&mut self, id: isize) -> Option<&mut ScrollView> {
+        match self.components.get_mut(id as usize)? {
+            Comp::ScrollView(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn scroll_offset(&self, id: isize) -> Option<(f32, f32)> {
+        Some(self.scroll_view(id)?.offset())
+    }
+
+    /// scrolls the scroll view `id` to `offset` in physical pixels, clamped to its content,
+    /// returns false if it did not move
+    pub(crate) fn scroll_to(&mut self, id: isize, offset: (f32, f32)) -> bool {
+        let Some(view) = self.scroll_view(id) else {
+            return false;
+        };
+        let old = view.offset();
+        let new = view.clamp(offset, &self.config);
+        if new == old {
+            return false;
+        }
+        self.scroll_view_mut(id).unwrap().set_offset(new);
+        let delta = self.pixels_to_ndc((old.0 - new.0, old.1 - new.1));
+        self.translate_subtree(id, delta, false);
+        self.request_redraw();
+        true
+    }
+
+    pub(crate) fn scroll_by(&mut self, id: isize, delta: (f32, f32)) -> bool {
+        match self.scroll_offset(id) {
+            Some(offset) => self.scroll_to(id, (offset.0 + delta.0, offset.1 + delta.1)),
+            None => false,
+        }
+    }
+
+    /// measures the content of every scroll view, again only once components moved, resized or
+    /// were hidden
+    pub(crate) fn update_scroll_content(&mut self) {
+        let layout: Vec<_> = (0..self.components.len() as isize)
+            .map(|id| (self.bounds(id), self.components[id as usize].hidden()))
+            .collect();
+        if layout == self.measured_layout {
+            return;
+        }
+        let views: Vec<isize> = (0..self.components.len() as isize)
+            .filter(|id| self.scroll_view(*id).is_some())
+            .collect();
+        for view in views {
+            let (left, top, width, height) = self.bounds(view);
+            let offset = self.scroll_offset(view).unwrap();
+            let mut content = (width, height);
+            let mut position = self.subtrees[view as usize].start;
+            while position < self.subtrees[view as usize].end {
+                let id = self.tree_order[position];
+                position += 1;
+                // nested scroll views only take their own bounds
+                if self.scroll_view(id).is_some() {
+                    position = self.subtrees[id as usize].end;
+                }
+                if self.components[id as usize].hidden() {
+                    continue;
+                }
+                let (x, y, w, h) = self.bounds(id);
+                content.0 = content.0.max(x + w - left + offset.0);
+                content.1 = content.1.max(y + h - top + offset.1);
+            }
+            self.scroll_view_mut(view)
+                .unwrap()
+                .set_content_size(content);
+        }
+        self.measured_layout = layout;
+    }
+
+    /// scrolls the nearest component around `target`, such as a scroll view or a text area,
+    /// that can still move in the direction of the wheel
+    pub(crate) fn wheel_scroll(&mut self, target: isize, delta: MouseScrollDelta) {
+        let (mut x, mut y) = match delta {
+            MouseScrollDelta::LineDelta(x, y) => (-x * LINE_HEIGHT, -y * LINE_HEIGHT),
+            MouseScrollDelta::PixelDelta(delta) => (-delta.x as f32, -delta.y as f32),
+        };
+        // shift turns a vertical wheel sideways
+        if self.modifiers.shift_key() && x == 0. {
+            (x, y) = (y, 0.);
+        }
+        self.update_scroll_content();
+        for id in self.path(target) {
+            if self.components[id as usize].wheel((x, y)) {
+                self.request_redraw();
+                return;
+            }
+            if self.scroll_by(id, (x, y)) {
+                return;
+            }
+        }
+    }
+
+    /// starts dragging a scrollbar thumb under the cursor, returns true if there was one on top
+    /// of everything else there
+    pub(crate) fn scrollbar_press(&mut self) -> bool {
+        self.update_scroll_content();
+        let shown: Vec<bool> = (0..self.components.len())
+            .map(|id| self.shown(id as isize))
+            .collect();
+        let point = self.cursor_pixels();
+        // thumbs are drawn along with their view, so the first view hit in reverse drawing
+        // order holds the topmost thumb unless something is drawn over it
+        let Some(view) = self
+            .draw_order(&shown)
+            .into_iter()
+            .rev()
+            .map(|id| id as isize)
+            .find(|id| self.hits(*id, point, None))
+        else {
+            return false;
+        };
+        let Some(scroll_view) = self.scroll_view(view) else {
+            return false;
+        };
+        let start = self.cursor_pixels_in(view);
+        let Some(axis) = scroll_view.thumb_at(start, &self.config) else {
+            return false;
+        };
+        let offset = scroll_view.offset();
+        self.scrollbar_drag = Some(ScrollbarDrag {
+            view,
+            axis,
+            start,
+            offset,
+        });
+        self.captured = Some(view);
+        true
+    }
+
+    /// follows the cursor with the held thumb, returns true while a thumb is held
+    pub(crate) fn scrollbar_move(&mut self) -> bool {
+        let Some(drag) = self.scrollbar_drag.as_ref() else {
+            return false;
+        };
+        let cursor = self.cursor_pixels_in(drag.view);
+        let view = self.scroll_view(drag.view).unwrap();
+        let ratio = view.thumb_ratio(drag.axis, &self.config);
+        let offset = match drag.axis {
+            Axis::Horizontal => (
+                drag.offset.0 + (cursor.0 - drag.start.0) * ratio,
+                drag.offset.1,
+            ),
+            Axis::Vertical => (
+                drag.offset.0,
+                drag.offset.1 + (cursor.1 - drag.start.1) * ratio,
+            ),
+        };
+        let id = drag.view;
+        self.scroll_to(id, offset);
+        true
+    }
+
+    /// lets go of the held thumb, returns true if one was held
+    pub(crate) fn scrollbar_release(&mut self, button: MouseButton) -> bool {
+        if button != MouseButton::Left || self.scrollbar_drag.take().is_none() {
+            return false;
+        }
+        self.captured = None;
+        true
+    }
+}
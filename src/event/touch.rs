@@ -0,0 +1,328 @@
+use std::f32::consts::PI;
+
+use winit::{
+    dpi::PhysicalPosition,
+    event::{ElementState, MouseButton, TouchPhase},
+    keyboard::ModifiersState,
+};
+
+use crate::{component::Component, state::State};
+
+use super::{Phase, Select};
+
+/// relative change in finger distance before two fingers count as a pinch
+const PINCH_THRESHOLD: f32 = 0.05;
+/// distance in physical pixels the fingers have to move together before they count as a pan
+const PAN_THRESHOLD: f32 = 10.;
+/// angle in radians the fingers have to turn before they count as a rotation
+const ROTATE_THRESHOLD: f32 = 0.1;
+
+/// Which gesture a [`GestureEvent`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GestureKind {
+    Pinch,
+    Pan,
+    Rotate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GestureState {
+    /// the gesture was just recognized, the deltas cover everything since the fingers landed
+    Began,
+    Changed,
+    /// a finger was lifted, the deltas are zero
+    Ended,
+}
+
+/// A two finger gesture over a component.
+///
+/// The fingers produce pinch, pan and rotate gestures independently once each moves past its
+/// threshold, delivered to the component under the point between the fingers when the second
+/// one landed.
+#[derive(Debug, Clone)]
+pub struct GestureEvent {
+    pub kind: GestureKind,
+    pub state: GestureState,
+    /// factor the distance between the fingers changed by since the previous event
+    pub scale: f32,
+    /// angle in radians the fingers turned clockwise since the previous event
+    pub rotation: f32,
+    /// distance in physical pixels the point between the fingers moved since the previous event
+    pub translation: (f32, f32),
+    /// point between the fingers in physical pixels from the top left corner of the window
+    pub position: (f32, f32),
+    /// point between the fingers in physical pixels from the top left corner of the component
+    /// handling the event
+    pub local: (f32, f32),
+    pub modifiers: ModifiersState,
+    pub phase: Phase,
+    propagation_stopped: bool,
+    default_prevented: bool,
+}
+
+/// Center, distance and angle of a pair of fingers.
+#[derive(Clone, Copy)]
+struct Pair {
+    center: (f32, f32),
+    distance: f32,
+    angle: f32,
+}
+
+impl Pair {
+    fn new(a: (f32, f32), b: (f32, f32)) -> Self {
+        Self {
+            center: ((a.0 + b.0) / 2., (a.1 + b.1) / 2.),
+            distance: (b.0 - a.0).hypot(b.1 - a.1).max(1.),
+            angle: (b.1 - a.1).atan2(b.0 - a.0),
+        }
+    }
+}
+
+/// the angle from `from` to `to` in radians, wrapped into -pi..pi
+fn turn(from: f32, to: f32) -> f32 {
+    let angle = (to - from) % (2. * PI);
+    match angle {
+        angle if angle > PI => angle - 2. * PI,
+        angle if angle < -PI => angle + 2. * PI,
+        angle => angle,
+    }
+}
+
+struct Gesture {
+    fingers: (u64, u64),
+    target: isize,
+    start: Pair,
+    last: Pair,
+    /// whether pinch, pan and rotate have been recognized
+    recognized: [bool; 3],
+}
+
+/// Fingers on the window, see [`State::touch`].
+#[derive(Default)]
+pub(crate) struct Touches {
+    /// finger ids with their positions in physical pixels, in the order they landed
+    fingers: Vec<(u64, (f32, f32))>,
+    /// the finger acting as the mouse, while it is the only one down
+    primary: Option<u64>,
+    /// whether lifting the primary finger clicks, not after it is left over from a gesture
+    primary_clicks: bool,
+    gesture: Option<Gesture>,
+}
+
+impl Touches {
+    fn get(&self, finger: u64) -> Option<(f32, f32)> {
+        self.fingers
+            .iter()
+            .find(|(id, _)| *id == finger)
+            .map(|(_, position)| *position)
+    }
+
+    fn set(&mut self, finger: u64, position: (f32, f32)) {
+        match self.fingers.iter_mut().find(|(id, _)| *id == finger) {
+            Some((_, old)) => *old = position,
+            None => self.fingers.push((finger, position)),
+        }
+    }
+}
+
+const KINDS: [GestureKind; 3] = [GestureKind::Pinch, GestureKind::Pan, GestureKind::Rotate];
+
+impl State {
+    /// Handles a finger landing, moving or lifting at `position` in physical pixels.
+    ///
+    /// A single finger drives the pointer like the left mouse button, a second finger cancels
+    /// that and starts recognizing gestures instead, between the two fingers that landed first.
+    /// Once a gesture ends with a single finger left, that finger takes the pointer back, but
+    /// lifting it does not click. Synthetic touches are injected through
+    /// [`crate::Context::inject_touch`], or [`crate::Headless::touch`] without a window.
+    pub fn touch(&mut self, finger: u64, phase: TouchPhase, position: (f32, f32)) {
+        let location = PhysicalPosition::new(position.0 as f64, position.1 as f64);
+        match phase {
+            TouchPhase::Started => {
+                self.touches.set(finger, position);
+                match self.touches.fingers.len() {
+                    1 => {
+                        self.touches.primary = Some(finger);
+                        self.touches.primary_clicks = true;
+                        self.cursor_moved(location);
+                        self.mouse_input(ElementState::Pressed, MouseButton::Left);
+                    }
+                    2 => {
+                        if self.touches.primary.take().is_some() {
+                            self.cancel_pointer();
+                        }
+                        self.start_gesture();
+                    }
+                    _ => {}
+                }
+            }
+            TouchPhase::Moved => {
+                self.touches.set(finger, position);
+                if self.touches.primary == Some(finger) {
+                    self.cursor_moved(location);
+                } else {
+                    self.update_gesture();
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.touches.fingers.retain(|(id, _)| *id != finger);
+                if self.touches.primary == Some(finger) {
+                    self.touches.primary = None;
+                    self.cursor_moved(location);
+                    match phase {
+                        TouchPhase::Ended => {
+                            // without a press to pair with, the release does not click
+                            if !self.touches.primary_clicks {
+                                self.pressed.remove(&MouseButton::Left);
+                            }
+                            self.mouse_input(ElementState::Released, MouseButton::Left)
+                        }
+                        _ => self.cancel_pointer(),
+                    }
+                    // fingers do not hover
+                    self.hover(None);
+                }
+                let lifted = self.touches.gesture.as_ref().is_some_and(|gesture| {
+                    gesture.fingers.0 == finger || gesture.fingers.1 == finger
+                });
+                if lifted {
+                    self.end_gesture();
+                    match self.touches.fingers[..] {
+                        [(left, position)] => self.promote(left, position),
+                        [] => {}
+                        _ => self.start_gesture(),
+                    }
+                }
+            }
+        }
+    }
+
+    /// abandons the left button interaction in progress without clicking or dropping
+    fn cancel_pointer(&mut self) {
+        self.pressed.remove(&MouseButton::Left);
+        self.cancel_long_press();
+        if let Some(drag) = self.drag.as_mut() {
+            drag.target = None;
+        }
+        self.drag_release();
+    }
+
+    /// hands the pointer to the finger left down at `position` after a gesture
+    fn promote(&mut self, finger: u64, position: (f32, f32)) {
+        self.touches.primary = Some(finger);
+        self.touches.primary_clicks = false;
+        self.cursor_moved(PhysicalPosition::new(position.0 as f64, position.1 as f64));
+        self.mouse_input(ElementState::Pressed, MouseButton::Left);
+    }
+
+    fn pair(&self, fingers: (u64, u64)) -> Option<Pair> {
+        let a = self.touches.get(fingers.0)?;
+        let b = self.touches.get(fingers.1)?;
+        Some(Pair::new(a, b))
+    }
+
+    /// starts recognizing gestures between the two fingers that landed first
+    fn start_gesture(&mut self) {
+        let [(a, _), (b, _), ..] = self.touches.fingers[..] else {
+            return;
+        };
+        let pair = self.pair((a, b)).unwrap();
+        // the pointer follows the gesture, so hit testing and local positions use its center
+        self.cursor_pos = (
+            (pair.center.0 / self.config.width as f32 * 2. - 1.) as f64,
+            (1. - pair.center.1 / self.config.height as f32 * 2.) as f64,
+        );
+        let Some(target) = self.get_element_by_pos().map(|comp| comp.get_id()) else {
+            return;
+        };
+        self.touches.gesture = Some(Gesture {
+            fingers: (a, b),
+            target,
+            start: pair,
+            last: pair,
+            recognized: [false; 3],
+        });
+    }
+
+    fn update_gesture(&mut self) {
+        let Some(gesture) = self.touches.gesture.as_ref() else {
+            return;
+        };
+        let Some(pair) = self.pair(gesture.fingers) else {
+            return;
+        };
+        let (start, last, target) = (gesture.start, gesture.last, gesture.target);
+        self.cursor_pos = (
+            (pair.center.0 / self.config.width as f32 * 2. - 1.) as f64,
+            (1. - pair.center.1 / self.config.height as f32 * 2.) as f64,
+        );
+        let passed = [
+            (pair.distance / start.distance - 1.).abs() > PINCH_THRESHOLD,
+            (pair.center.0 - start.center.0).hypot(pair.center.1 - start.center.1) > PAN_THRESHOLD,
+            turn(start.angle, pair.angle).abs() > ROTATE_THRESHOLD,
+        ];
+        for (index, kind) in KINDS.into_iter().enumerate() {
+            let gesture = self.touches.gesture.as_mut().unwrap();
+            let state = match (gesture.recognized[index], passed[index]) {
+                (true, _) => GestureState::Changed,
+                (false, true) => GestureState::Began,
+                (false, false) => continue,
+            };
+            gesture.recognized[index] = true;
+            let from = match state {
+                GestureState::Began => start,
+                _ => last,
+            };
+            let mut event = self.gesture_event(kind, state, from, pair);
+            self.dispatch(target, &mut event, select(kind), |_| &None);
+        }
+        if let Some(gesture) = self.touches.gesture.as_mut() {
+            gesture.last = pair;
+        }
+    }
+
+    fn end_gesture(&mut self) {
+        let Some(gesture) = self.touches.gesture.take() else {
+            return;
+        };
+        for (index, kind) in KINDS.into_iter().enumerate() {
+            if gesture.recognized[index] {
+                let mut event =
+                    self.gesture_event(kind, GestureState::Ended, gesture.last, gesture.last);
+                self.dispatch(gesture.target, &mut event, select(kind), |_| &None);
+            }
+        }
+    }
+
+    fn gesture_event(
+        &self,
+        kind: GestureKind,
+        state: GestureState,
+        from: Pair,
+        to: Pair,
+    ) -> GestureEvent {
+        GestureEvent {
+            kind,
+            state,
+            scale: to.distance / from.distance,
+            rotation: turn(from.angle, to.angle),
+            translation: (to.center.0 - from.center.0, to.center.1 - from.center.1),
+            position: to.center,
+            local: (0., 0.),
+            modifiers: self.modifiers,
+            phase: Phase::Target,
+            propagation_stopped: false,
+            default_prevented: false,
+        }
+    }
+}
+
+fn select(kind: GestureKind) -> Select<GestureEvent> {
+    match kind {
+        GestureKind::Pinch => |h| &h.pinch,
+        GestureKind::Pan => |h| &h.pan,
+        GestureKind::Rotate => |h| &h.rotate,
+    }
+}
+
+impl_propagation!(GestureEvent, local);
@@ -0,0 +1,219 @@
+use std::{iter::once, path::PathBuf, time::Duration};
+
+use winit::{
+    dpi::PhysicalPosition,
+    event::{ElementState, Ime, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent},
+    keyboard::{Key, ModifiersState},
+};
+
+use crate::{
+    component::Comp,
+    context::Context,
+    state::{Gpu, State},
+    Application,
+};
+
+/// An application running without a window, driven by hand.
+///
+/// Components are laid out as in a window of the given size and receive the input fed in here,
+/// but nothing is drawn unless it is created with [`Headless::with_gpu`]. Return a [`crate::ManualClock`] from [`Application::clock`] and call
+/// [`Headless::update`] after advancing it to step through animations and timers, for example
+/// in tests.
+pub struct Headless {
+    state: State,
+}
+
+impl Headless {
+    /// `width` and `height` in physical pixels
+    pub fn new(app: impl Application, width: u32, height: u32) -> Self {
+        Self {
+            state: State::headless(app, width, height),
+        }
+    }
+
+    /// like [`Headless::new`] but drawing with a GPU, see [`Headless::render`], `None` when
+    /// there is no adapter to draw with
+    pub async fn with_gpu(app: impl Application, width: u32, height: u32) -> Option<Self> {
+        Some(Self {
+            state: State::offscreen(app, width, height).await?,
+        })
+    }
+
+    /// draws a frame and reads it back as rows of RGBA bytes from the top left corner, `None`
+    /// without a GPU
+    pub fn render(&mut self) -> Option<Vec<u8>> {
+        let (width, height) = (self.state.config.width, self.state.config.height);
+        let texture = {
+            let device = &self.state.gpu.as_ref()?.device;
+            device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Headless Texture"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.state.config.format,
+                usage: self.state.config.usage,
+                view_formats: &[],
+            })
+        };
+        self.state
+            .draw(&texture.create_view(&wgpu::TextureViewDescriptor::default()));
+
+        let Gpu { device, queue, .. } = self.state.gpu.as_ref()?;
+        // rows of a copy are aligned, the padding is dropped when reading them back
+        let row = width * 4;
+        let padded_row = row.next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Headless Buffer"),
+            size: (padded_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Headless Encoder"),
+        });
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size(),
+        );
+        queue.submit(once(encoder.finish()));
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::Maintain::Wait);
+        let data = slice.get_mapped_range();
+        Some(
+            data.chunks(padded_row as usize)
+                .flat_map(|line| &line[..row as usize])
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// moves the pointer to `position` in physical pixels from the top left corner of the window
+    pub fn move_to(&mut self, position: (f32, f32)) {
+        let position = PhysicalPosition::new(position.0 as f64, position.1 as f64);
+        self.state.cursor_moved(position);
+    }
+
+    pub fn press(&mut self, button: MouseButton) {
+        self.state.mouse_input(ElementState::Pressed, button);
+    }
+
+    pub fn release(&mut self, button: MouseButton) {
+        self.state.mouse_input(ElementState::Released, button);
+    }
+
+    /// moves the pointer to `position` and clicks the left button there
+    pub fn click(&mut self, position: (f32, f32)) {
+        self.move_to(position);
+        self.press(MouseButton::Left);
+        self.release(MouseButton::Left);
+    }
+
+    pub fn wheel(&mut self, delta: MouseScrollDelta) {
+        self.state.mouse_wheel(delta);
+    }
+
+    /// a finger landing, moving or lifting at `position` in physical pixels, see
+    /// [`Context::inject_touch`]
+    pub fn touch(&mut self, finger: u64, phase: TouchPhase, position: (f32, f32)) {
+        self.state.touch(finger, phase, position);
+    }
+
+    /// an event of the input method, delivered to the focused component
+    pub fn ime(&mut self, ime: Ime) {
+        self.state.input(&WindowEvent::Ime(ime));
+    }
+
+    /// a key pressed or released with the current modifiers, see [`Headless::set_modifiers`],
+    /// producing the text of `Key::Character` when pressed
+    pub fn key(&mut self, key: Key, state: ElementState) {
+        self.state.inject_key(key, state);
+    }
+
+    /// presses and releases `key`
+    pub fn tap(&mut self, key: Key) {
+        self.key(key.clone(), ElementState::Pressed);
+        self.key(key, ElementState::Released);
+    }
+
+    /// a file dragged from outside the window hovering at `position` in physical pixels
+    pub fn hover_file(&mut self, path: impl Into<PathBuf>, position: (f32, f32)) {
+        self.state.file_hovered(path.into(), position);
+    }
+
+    /// the hovering files leaving the window
+    pub fn cancel_file_hover(&mut self) {
+        self.state.file_hover_cancelled();
+    }
+
+    /// drops `paths` together where the files were last hovering, or under the pointer
+    pub fn drop_files(&mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) {
+        for path in paths {
+            self.state.file_dropped(path.into());
+        }
+        self.state.flush_file_drop();
+    }
+
+    pub fn set_modifiers(&mut self, modifiers: ModifiersState) {
+        self.state.modifiers = modifiers;
+    }
+
+    /// the user asking to close the window, see [`Application::on_close_requested`]
+    pub fn request_close(&mut self) {
+        self.state.close_requested();
+    }
+
+    /// whether the window would have closed, after [`crate::Context::exit`] or an accepted
+    /// [`Headless::request_close`]
+    pub fn exit_requested(&self) -> bool {
+        self.state.exit_requested
+    }
+
+    /// fires the expired timers and moves animations to the current time of the clock, as the
+    /// event loop does before drawing a frame
+    pub fn update(&mut self) {
+        self.state.update_timers();
+        self.state.update();
+    }
+
+    /// whether an animation or spring still needs another frame
+    pub fn animating(&self) -> bool {
+        self.state.animating()
+    }
+
+    /// how long until a timer such as a long press fires, by the clock of the application
+    pub fn next_timeout(&self) -> Option<Duration> {
+        self.state.next_timeout()
+    }
+
+    /// the topmost component the pointer would hit at `position` in physical pixels
+    pub fn component_at(&self, position: (f32, f32)) -> Option<isize> {
+        self.state.hit_test(position, None)
+    }
+
+    pub fn component(&self, id: isize) -> &Comp {
+        &self.state.components[id as usize]
+    }
+
+    pub fn focused(&self) -> Option<isize> {
+        self.state.focused
+    }
+
+    /// acts on the application like a handler would
+    pub fn context(&mut self) -> Context<'_> {
+        self.state.as_ctx()
+    }
+}
@@ -1,10 +1,25 @@
+mod animation;
 mod app;
 mod backend;
+mod clipboard;
+mod clock;
 pub mod component;
 mod context;
 mod event;
+mod headless;
+mod shortcut;
 mod state;
 mod vertex;
 
+pub use animation::{Animation, AnimationId, Easing, Property, Spring, Timeline, Tween};
 pub use app::Application;
 pub use backend::run;
+pub use clipboard::{Clipboard, MemoryClipboard, SystemClipboard};
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use context::Context;
+pub use event::{
+    DragEvent, DragKind, FileDropEvent, GestureEvent, GestureKind, GestureState, KeyboardEvent,
+    Phase, PointerConfig, PointerEvent, PointerKind, ScrollEvent,
+};
+pub use headless::Headless;
+pub use shortcut::{Shortcut, ShortcutError, Shortcuts};
@@ -0,0 +1,307 @@
+use std::{cell::RefCell, fmt, rc::Rc, str::FromStr};
+
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+use crate::context::Context;
+
+type Action = Rc<RefCell<dyn FnMut(Context)>>;
+
+/// A key combined with the exact set of modifiers that must be held, such as `Ctrl+Shift+P`.
+///
+/// Character keys are compared case-insensitively, so `Ctrl+Shift+P` matches whether the
+/// layout reports `p` or `P` while shift is held.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    pub modifiers: ModifiersState,
+    pub key: Key,
+}
+
+impl Shortcut {
+    pub fn new(key: Key) -> Self {
+        let key = match key {
+            Key::Character(c) => Key::Character(c.to_lowercase().into()),
+            key => key,
+        };
+        Self {
+            modifiers: ModifiersState::empty(),
+            key,
+        }
+    }
+
+    pub fn ctrl(mut self) -> Self {
+        self.modifiers |= ModifiersState::CONTROL;
+        self
+    }
+
+    pub fn shift(mut self) -> Self {
+        self.modifiers |= ModifiersState::SHIFT;
+        self
+    }
+
+    pub fn alt(mut self) -> Self {
+        self.modifiers |= ModifiersState::ALT;
+        self
+    }
+
+    /// the Windows, Command or Meta key
+    pub fn super_key(mut self) -> Self {
+        self.modifiers |= ModifiersState::SUPER;
+        self
+    }
+
+    pub fn matches(&self, key: &Key, modifiers: ModifiersState) -> bool {
+        modifiers == self.modifiers && Shortcut::new(key.clone()).key == self.key
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutError;
+
+    /// parses shortcuts written like `Ctrl+Shift+P`, `Alt+F4` or `Escape`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+        // `Ctrl++` binds the plus key
+        if s.ends_with("++") {
+            parts.truncate(parts.len() - 2);
+            parts.push("+");
+        }
+        let key = parts.pop().unwrap_or_default();
+        let mut modifiers = ModifiersState::empty();
+        for part in parts {
+            modifiers |= match part.to_lowercase().as_str() {
+                "ctrl" | "control" => ModifiersState::CONTROL,
+                "shift" => ModifiersState::SHIFT,
+                "alt" | "option" => ModifiersState::ALT,
+                "super" | "cmd" | "command" | "meta" | "win" => ModifiersState::SUPER,
+                _ => return Err(ShortcutError::InvalidModifier(part.into())),
+            };
+        }
+        let key = match key.chars().count() {
+            1 => Key::Character(key.into()),
+            _ => Key::Named(named_key(key).ok_or(ShortcutError::InvalidKey(key.into()))?),
+        };
+        Ok(Shortcut {
+            modifiers,
+            ..Shortcut::new(key)
+        })
+    }
+}
+
+/// the names of the keys that are not characters, the first one is used for display
+const NAMED_KEYS: [(NamedKey, &[&str]); 27] = [
+    (NamedKey::Escape, &["Esc", "Escape"]),
+    (NamedKey::Enter, &["Enter", "Return"]),
+    (NamedKey::Tab, &["Tab"]),
+    (NamedKey::Space, &["Space"]),
+    (NamedKey::Backspace, &["Backspace"]),
+    (NamedKey::Delete, &["Delete", "Del"]),
+    (NamedKey::Insert, &["Insert", "Ins"]),
+    (NamedKey::Home, &["Home"]),
+    (NamedKey::End, &["End"]),
+    (NamedKey::PageUp, &["PageUp"]),
+    (NamedKey::PageDown, &["PageDown"]),
+    (NamedKey::ArrowUp, &["Up", "ArrowUp"]),
+    (NamedKey::ArrowDown, &["Down", "ArrowDown"]),
+    (NamedKey::ArrowLeft, &["Left", "ArrowLeft"]),
+    (NamedKey::ArrowRight, &["Right", "ArrowRight"]),
+    (NamedKey::F1, &["F1"]),
+    (NamedKey::F2, &["F2"]),
+    (NamedKey::F3, &["F3"]),
+    (NamedKey::F4, &["F4"]),
+    (NamedKey::F5, &["F5"]),
+    (NamedKey::F6, &["F6"]),
+    (NamedKey::F7, &["F7"]),
+    (NamedKey::F8, &["F8"]),
+    (NamedKey::F9, &["F9"]),
+    (NamedKey::F10, &["F10"]),
+    (NamedKey::F11, &["F11"]),
+    (NamedKey::F12, &["F12"]),
+];
+
+fn named_key(name: &str) -> Option<NamedKey> {
+    NAMED_KEYS
+        .iter()
+        .find(|(_, names)| names.iter().any(|known| known.eq_ignore_ascii_case(name)))
+        .map(|(key, _)| *key)
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = [
+            (ModifiersState::CONTROL, "Ctrl+"),
+            (ModifiersState::SHIFT, "Shift+"),
+            (ModifiersState::ALT, "Alt+"),
+            (ModifiersState::SUPER, "Super+"),
+        ];
+        for (modifier, name) in names {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+        match &self.key {
+            Key::Character(c) => f.write_str(&c.to_uppercase()),
+            Key::Named(named) => match NAMED_KEYS.iter().find(|(key, _)| key == named) {
+                Some((_, names)) => f.write_str(names[0]),
+                None => write!(f, "{named:?}"),
+            },
+            key => write!(f, "{key:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutError {
+    /// the shortcut is already bound to another action
+    Conflict(Shortcut),
+    InvalidModifier(String),
+    InvalidKey(String),
+}
+
+impl fmt::Display for ShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortcutError::Conflict(shortcut) => write!(f, "{shortcut} is already registered"),
+            ShortcutError::InvalidModifier(modifier) => write!(f, "unknown modifier {modifier:?}"),
+            ShortcutError::InvalidKey(key) => write!(f, "unknown key {key:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutError {}
+
+/// Application wide keyboard shortcuts, triggered regardless of which component is focused.
+///
+/// Register them in [`crate::Application::shortcuts`] or at runtime through
+/// [`crate::Context::shortcuts`]. A shortcut takes precedence over the keyboard handlers and
+/// built-in behavior of components.
+#[derive(Default)]
+pub struct Shortcuts {
+    entries: Vec<(Shortcut, Action)>,
+}
+
+impl Shortcuts {
+    /// binds `action` to `shortcut`, failing if the shortcut is already bound
+    pub fn register(
+        &mut self,
+        shortcut: Shortcut,
+        action: impl FnMut(Context) + 'static,
+    ) -> Result<(), ShortcutError> {
+        if self.contains(&shortcut) {
+            return Err(ShortcutError::Conflict(shortcut));
+        }
+        self.entries.push((shortcut, Rc::new(RefCell::new(action))));
+        Ok(())
+    }
+
+    /// removes the binding of `shortcut`, returns false if it was not bound
+    pub fn unregister(&mut self, shortcut: &Shortcut) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|(bound, _)| bound != shortcut);
+        self.entries.len() != len
+    }
+
+    pub fn contains(&self, shortcut: &Shortcut) -> bool {
+        self.entries.iter().any(|(bound, _)| bound == shortcut)
+    }
+
+    pub(crate) fn find(&self, key: &Key, modifiers: ModifiersState) -> Option<Action> {
+        self.entries
+            .iter()
+            .find(|(shortcut, _)| shortcut.matches(key, modifiers))
+            .map(|(_, action)| action.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Shortcut, ShortcutError> {
+        s.parse()
+    }
+
+    fn character(c: &str) -> Shortcut {
+        Shortcut::new(Key::Character(c.into()))
+    }
+
+    #[test]
+    fn parses_modifiers_in_any_case_and_order() {
+        let expected = character("p").ctrl().shift();
+        assert_eq!(parse("Ctrl+Shift+P"), Ok(expected.clone()));
+        assert_eq!(parse("shift + control + p"), Ok(expected));
+        assert_eq!(parse("Option+Cmd+A"), Ok(character("a").alt().super_key()));
+        assert_eq!(parse("Ctrl++"), Ok(character("+").ctrl()));
+    }
+
+    #[test]
+    fn parses_named_keys_and_their_aliases() {
+        let escape = Shortcut::new(Key::Named(NamedKey::Escape));
+        assert_eq!(parse("Esc"), Ok(escape.clone()));
+        assert_eq!(parse("escape"), Ok(escape));
+        assert_eq!(
+            parse("Alt+F4"),
+            Ok(Shortcut::new(Key::Named(NamedKey::F4)).alt())
+        );
+        assert_eq!(
+            parse("ArrowUp"),
+            Ok(Shortcut::new(Key::Named(NamedKey::ArrowUp)))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert_eq!(
+            parse("Hyper+A"),
+            Err(ShortcutError::InvalidModifier("Hyper".into()))
+        );
+        assert_eq!(
+            parse("Ctrl+Foo"),
+            Err(ShortcutError::InvalidKey("Foo".into()))
+        );
+        assert_eq!(parse("Ctrl+"), Err(ShortcutError::InvalidKey("".into())));
+        assert_eq!(parse(""), Err(ShortcutError::InvalidKey("".into())));
+    }
+
+    #[test]
+    fn displays_what_parses_back() {
+        for text in [
+            "Ctrl+Shift+P",
+            "Alt+F4",
+            "Esc",
+            "Super+PageDown",
+            "Ctrl++",
+            "Up",
+        ] {
+            let shortcut = parse(text).unwrap();
+            assert_eq!(shortcut.to_string(), text);
+            assert_eq!(parse(&shortcut.to_string()), Ok(shortcut));
+        }
+        let unlisted = Shortcut::new(Key::Named(NamedKey::MediaPlay)).ctrl();
+        assert_eq!(unlisted.to_string(), "Ctrl+MediaPlay");
+    }
+
+    #[test]
+    fn matches_characters_regardless_of_case() {
+        let shortcut = parse("Ctrl+Shift+P").unwrap();
+        let both = ModifiersState::CONTROL | ModifiersState::SHIFT;
+        assert!(shortcut.matches(&Key::Character("P".into()), both));
+        assert!(shortcut.matches(&Key::Character("p".into()), both));
+        assert!(!shortcut.matches(&Key::Character("P".into()), ModifiersState::CONTROL));
+    }
+
+    #[test]
+    fn registering_a_bound_shortcut_conflicts() {
+        let mut shortcuts = Shortcuts::default();
+        let close = parse("Ctrl+F4").unwrap();
+        assert!(shortcuts.register(close.clone(), |_| {}).is_ok());
+        let err = shortcuts.register(close.clone(), |_| {}).unwrap_err();
+        assert_eq!(err, ShortcutError::Conflict(close.clone()));
+        assert_eq!(err.to_string(), "Ctrl+F4 is already registered");
+
+        // other modifiers make another shortcut
+        assert!(shortcuts.register(close.clone().shift(), |_| {}).is_ok());
+        assert!(shortcuts.unregister(&close));
+        assert!(!shortcuts.unregister(&close));
+        assert!(shortcuts.register(close, |_| {}).is_ok());
+    }
+}
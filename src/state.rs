@@ -1,21 +1,83 @@
-use std::{iter::once, sync::Arc};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    iter::once,
+    mem::{discriminant, Discriminant},
+    ops::Range,
+    rc::Rc,
+    sync::Arc,
+    time::Instant,
+};
 
-use winit::{dpi::PhysicalSize, window::Window};
+use winit::{dpi::PhysicalSize, event::MouseButton, keyboard::ModifiersState, window::Window};
 
 use crate::{
-    component::{Component, Components, IntoComponent},
+    animation::Animations,
+    clipboard::Clipboard,
+    clock::Clock,
+    component::{
+        create_stencil_view, Affine, Clip, Comp, Component, Components, FocusRing, IntoComponent,
+        Rect, MAX_CLIPS,
+    },
     context::Context,
+    event::{Clicks, Drag, FileDrop, PointerConfig, ScrollbarDrag, Touches},
+    shortcut::Shortcuts,
     Application,
 };
 
-pub struct State {
-    pub surface: wgpu::Surface<'static>,
+type CloseRequested = Rc<RefCell<dyn FnMut(Context) -> bool>>;
+
+/// The surface of the window and the device drawing on it.
+pub struct Gpu {
+    /// `None` when drawing offscreen, see [`State::offscreen`]
+    pub surface: Option<wgpu::Surface<'static>>,
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
+    /// masks text to rounded ancestors, the size of the surface
+    pub stencil: wgpu::TextureView,
+}
+
+pub struct State {
+    /// `None` when running headless, nothing is drawn then
+    pub gpu: Option<Gpu>,
     pub config: wgpu::SurfaceConfiguration,
-    pub window: Arc<Window>,
+    pub window: Option<Arc<Window>>,
     pub components: Components,
+    /// parent id of each component, indexed by id
+    pub parents: Vec<Option<isize>>,
+    /// component ids in tree pre-order, starting with the root
+    pub tree_order: Vec<isize>,
+    /// the range of `tree_order` holding the descendants of each component, indexed by id
+    pub(crate) subtrees: Vec<Range<usize>>,
+    /// a view handed to [`Context::set_view`], swapped in before the next frame
+    pub(crate) pending_view: Option<Comp>,
     pub cursor_pos: (f64, f64),
+    pub focused: Option<isize>,
+    /// whether focus was last moved with the keyboard, which shows the focus ring
+    pub focus_visible: bool,
+    pub focus_ring: FocusRing,
+    pub pressed: HashMap<MouseButton, isize>,
+    pub hovered: Option<isize>,
+    /// the component whose handler is running
+    pub current: Option<isize>,
+    /// component receiving all pointer events regardless of what is under the cursor
+    pub captured: Option<isize>,
+    pub pointer_config: PointerConfig,
+    pub(crate) clicks: Clicks,
+    pub(crate) drag: Option<Drag>,
+    pub(crate) scrollbar_drag: Option<ScrollbarDrag>,
+    pub(crate) file_drop: FileDrop,
+    pub(crate) touches: Touches,
+    pub drop_highlight: Rect,
+    pub modifiers: ModifiersState,
+    pub clipboard: Box<dyn Clipboard>,
+    pub clock: Box<dyn Clock>,
+    pub shortcuts: Shortcuts,
+    pub close_requested: CloseRequested,
+    pub exit_requested: bool,
+    pub(crate) animations: Animations,
+    /// bounds and visibility of every component when the scroll views were last measured
+    pub(crate) measured_layout: Vec<((f32, f32, f32, f32), bool)>,
 }
 
 impl State {
@@ -73,68 +135,367 @@ impl State {
         };
         surface.configure(&device, &config);
 
-        let mut root = app.view().into_comp();
-        root.init(&device, &queue, &config);
-        let mut components = root.collect_and_init(&device, &queue, &config);
-        components.push(root);
-        components.sort_by_key(|a| std::cmp::Reverse(a.depth()));
-        components
-            .iter_mut()
-            .enumerate()
-            .for_each(|(index, comp)| comp.set_id(index as _));
-        let cursor_pos = (0., 0.);
+        let stencil = create_stencil_view(&device, &config);
+        let gpu = Gpu {
+            surface: Some(surface),
+            device,
+            queue,
+            stencil,
+        };
+        Self::build(app, config, Some(window), Some(gpu))
+    }
 
-        Self {
-            surface,
+    /// a state without a window or GPU, laid out as if in a window of `width` by `height`
+    /// physical pixels, see [`crate::Headless`]
+    pub fn headless(app: impl Application, width: u32, height: u32) -> Self {
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            width,
+            height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        Self::build(app, config, None, None)
+    }
+
+    /// a state without a window drawing into textures instead, `None` when there is no adapter
+    /// to draw with
+    pub async fn offscreen(app: impl Application, width: u32, height: u32) -> Option<Self> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        });
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .ok()?;
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            width,
+            height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        let stencil = create_stencil_view(&device, &config);
+        let gpu = Gpu {
+            surface: None,
             device,
             queue,
+            stencil,
+        };
+        Some(Self::build(app, config, None, Some(gpu)))
+    }
+
+    fn build(
+        app: impl Application,
+        config: wgpu::SurfaceConfiguration,
+        window: Option<Arc<Window>>,
+        gpu: Option<Gpu>,
+    ) -> Self {
+        let clipboard = app.clipboard();
+        let clock = app.clock();
+        let pointer_config = app.pointer_config();
+        let mut shortcuts = Shortcuts::default();
+        app.shortcuts(&mut shortcuts);
+        let close_requested = Rc::new(RefCell::new(app.on_close_requested()));
+        let view = View::assemble(app.view().into_comp(), &config, gpu.as_ref());
+        let cursor_pos = (0., 0.);
+        let mut focus_ring = FocusRing::default();
+        let mut drop_highlight = Rect::new().color(0.3, 0.6, 1., 0.3);
+        if let Some(Gpu { device, queue, .. }) = &gpu {
+            focus_ring.init(device, queue, &config);
+            drop_highlight.init(device, queue, &config);
+        }
+
+        Self {
+            gpu,
             config,
             window,
-            components,
+            components: view.components,
+            parents: view.parents,
+            tree_order: view.tree_order,
+            subtrees: view.subtrees,
+            pending_view: None,
             cursor_pos,
+            focused: None,
+            focus_visible: false,
+            focus_ring,
+            pressed: HashMap::new(),
+            hovered: None,
+            current: None,
+            captured: None,
+            pointer_config,
+            clicks: Clicks::default(),
+            drag: None,
+            scrollbar_drag: None,
+            file_drop: FileDrop::default(),
+            touches: Touches::default(),
+            drop_highlight,
+            modifiers: ModifiersState::empty(),
+            clipboard,
+            clock,
+            shortcuts,
+            close_requested,
+            exit_requested: false,
+            animations: Animations::default(),
+            measured_layout: vec![],
         }
     }
 
-    pub fn window(&self) -> &Window {
-        &self.window
+    /// `None` when running headless
+    pub fn window(&self) -> Option<&Window> {
+        self.window.as_deref()
+    }
+
+    pub fn request_redraw(&self) {
+        if let Some(window) = self.window() {
+            window.request_redraw();
+        }
     }
 
     pub fn size(&self) -> PhysicalSize<u32> {
-        self.window().inner_size()
+        match self.window() {
+            Some(window) => window.inner_size(),
+            None => PhysicalSize::new(self.config.width, self.config.height),
+        }
     }
 
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
-            self.surface.configure(&self.device, &self.config);
-            self.window().request_redraw();
+            if let Some(gpu) = &mut self.gpu {
+                if let Some(surface) = &gpu.surface {
+                    surface.configure(&gpu.device, &self.config);
+                }
+                gpu.stencil = create_stencil_view(&gpu.device, &self.config);
+            }
+            self.request_redraw();
         }
     }
 
-    pub fn update(&mut self) {}
+    /// the current time according to the clock of the application
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
 
-    pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
-        self.components
-            .iter_mut()
-            .for_each(|component| component.prepare(&self.device, &self.queue, &self.config));
+    pub fn update(&mut self) {
+        if let Some(root) = self.pending_view.take() {
+            self.replace_view(root);
+        }
+        self.update_animations();
+        self.update_springs();
+    }
+
+    /// asks the application whether the window may close and exits if it agrees
+    pub fn close_requested(&mut self) {
+        let close_requested = self.close_requested.clone();
+        if (close_requested.borrow_mut())(self.as_ctx()) {
+            self.exit_requested = true;
+        }
+    }
+
+    /// moves the component `id` and all of its descendants by `delta` in screen space
+    pub fn translate(&mut self, id: isize, delta: (f32, f32)) {
+        self.translate_subtree(id, delta, true);
+    }
+
+    /// moves the descendants of `id`, and `id` itself if `include_self`, by `delta` in screen
+    /// space
+    pub(crate) fn translate_subtree(&mut self, id: isize, delta: (f32, f32), include_self: bool) {
+        for index in 0..self.components.len() {
+            if (include_self || index as isize != id) && self.path(index as isize).contains(&id) {
+                let comp = &mut self.components[index];
+                let (x, y) = comp.get_position();
+                comp.set_position((x + delta.0, y + delta.1));
+            }
+        }
+    }
+
+    /// whether the component `id` and all of its ancestors are visible
+    pub fn shown(&self, id: isize) -> bool {
+        self.path(id)
+            .iter()
+            .all(|id| !self.components[*id as usize].hidden())
+    }
+
+    /// left, top, width and height of the component `id` in physical pixels
+    pub(crate) fn bounds(&self, id: isize) -> (f32, f32, f32, f32) {
+        let comp = &self.components[id as usize];
+        let (x, y) = comp.get_position();
+        let (width, height) = comp.get_size();
+        let (screen_width, screen_height) = (self.config.width as f32, self.config.height as f32);
+        (
+            (x + 1.) / 2. * screen_width,
+            (1. - y) / 2. * screen_height,
+            width / 2. * screen_width,
+            height / 2. * screen_height,
+        )
+    }
+
+    /// the transform of the component `id` alone, in physical pixels
+    fn local_transform(&self, id: isize) -> Affine {
+        self.components[id as usize]
+            .get_transform()
+            .matrix(self.bounds(id))
+    }
+
+    /// the transforms of the component `id` and all of its ancestors combined
+    pub(crate) fn world_transform(&self, id: isize) -> Affine {
+        match self.parents[id as usize] {
+            Some(parent) => self.content_transform(parent) * self.local_transform(id),
+            None => self.local_transform(id),
+        }
+    }
 
-        let output = self.surface.get_current_texture()?;
+    /// the transform the children of `id` are drawn with before their own, such as the view of
+    /// a camera
+    pub(crate) fn content_transform(&self, id: isize) -> Affine {
+        let children = self.components[id as usize].children_transform(self.bounds(id));
+        self.world_transform(id) * children
+    }
+
+    /// the scissor rect left by the ancestors of `id` that clip their children, `None` if
+    /// nothing clips it
+    fn clip_rect(&self, id: isize) -> Option<(u32, u32, u32, u32)> {
+        let (screen_width, screen_height) = (self.config.width as f32, self.config.height as f32);
+        let mut clip: Option<(f32, f32, f32, f32)> = None;
+        for ancestor in self.path(id).into_iter().skip(1) {
+            if !self.components[ancestor as usize].clips_children() {
+                continue;
+            }
+            let (x, y, w, h) = self
+                .world_transform(ancestor)
+                .bounding_box(self.bounds(ancestor));
+            let (left, top, right, bottom) = clip.unwrap_or((0., 0., screen_width, screen_height));
+            clip = Some((left.max(x), top.max(y), right.min(x + w), bottom.min(y + h)));
+        }
+        clip.map(|(left, top, right, bottom)| {
+            let (left, top) = (left.clamp(0., screen_width), top.clamp(0., screen_height));
+            let (right, bottom) = (
+                right.clamp(left, screen_width),
+                bottom.clamp(top, screen_height),
+            );
+            (
+                left as u32,
+                top as u32,
+                (right - left) as u32,
+                (bottom - top) as u32,
+            )
+        })
+    }
+
+    /// the ancestors of `id` that clip their children, innermost first, with rounded corners or
+    /// transforms the scissor rect of [`State::clip_rect`] cannot follow
+    fn clip_shapes(&self, id: isize) -> Vec<Clip> {
+        self.path(id)
+            .into_iter()
+            .skip(1)
+            .filter_map(|ancestor| {
+                let comp = &self.components[ancestor as usize];
+                let world = self.world_transform(ancestor);
+                let shaped = comp.corner_radii().iter().any(|radius| *radius > 0.)
+                    || world != Affine::IDENTITY;
+                (comp.clips_children() && shaped).then(|| Clip {
+                    position: comp.get_position(),
+                    size: comp.get_size(),
+                    radii: comp.corner_radii(),
+                    inverse: world.inverse().unwrap_or_default(),
+                })
+            })
+            .take(MAX_CLIPS)
+            .collect()
+    }
+
+    pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        let Some(surface) = self.gpu.as_ref().and_then(|gpu| gpu.surface.as_ref()) else {
+            return Ok(());
+        };
+        let output = surface.get_current_texture()?;
         let view = output
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
+        self.draw(&view);
+        output.present();
+        Ok(())
+    }
 
-        let mut encoder = self
-            .device
-            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("Render Encoder"),
-            });
+    /// draws a frame into `view` of a texture in the format and size of the configuration
+    pub(crate) fn draw(&mut self, view: &wgpu::TextureView) {
+        if self.gpu.is_none() {
+            return;
+        }
+        self.update_scroll_content();
+        let shown: Vec<bool> = (0..self.components.len())
+            .map(|id| self.shown(id as isize))
+            .collect();
+        let full = (0, 0, self.config.width, self.config.height);
+        let clips: Vec<(u32, u32, u32, u32)> = (0..self.components.len())
+            .map(|id| self.clip_rect(id as isize).unwrap_or(full))
+            .collect();
+        let visible: Vec<bool> = shown
+            .iter()
+            .zip(clips.iter())
+            .map(|(shown, (_, _, w, h))| *shown && *w > 0 && *h > 0)
+            .collect();
+        for id in (0..self.components.len()).filter(|id| visible[*id]) {
+            let shapes = self.clip_shapes(id as isize);
+            let world = self.world_transform(id as isize);
+            let comp = &mut self.components[id];
+            comp.set_clips(&shapes);
+            comp.set_world_transform(world, &self.config);
+        }
+        let Gpu {
+            device,
+            queue,
+            stencil,
+            ..
+        } = self.gpu.as_ref().unwrap();
+        self.components
+            .iter_mut()
+            .zip(visible.iter())
+            .filter(|(_, visible)| **visible)
+            .for_each(|(component, _)| component.prepare(device, queue, &self.config));
+        let focus_ring = self
+            .focused
+            .filter(|id| self.focus_visible && shown[*id as usize])
+            .map(|id| (&self.components[id as usize], self.world_transform(id)))
+            .map(|(comp, world)| (comp.get_position(), comp.get_size(), world));
+        if let Some((position, size, world)) = focus_ring {
+            self.focus_ring.layout(position, size, world, &self.config);
+        }
+        let drop_target = self
+            .drag
+            .as_ref()
+            .and_then(|drag| drag.target)
+            .or(self.file_drop.target)
+            .filter(|id| shown[*id as usize])
+            .map(|id| (&self.components[id as usize], self.world_transform(id)))
+            .map(|(comp, world)| (comp.get_position(), comp.get_size(), world));
+        if let Some((position, size, world)) = drop_target {
+            self.drop_highlight.set_position(position);
+            self.drop_highlight.set_size(size);
+            self.drop_highlight.set_world_transform(world, &self.config);
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Render Encoder"),
+        });
 
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Render Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
+                    view,
                     resolve_target: None,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color {
@@ -146,27 +507,227 @@ impl State {
                         store: wgpu::StoreOp::Store,
                     },
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: stencil,
+                    depth_ops: None,
+                    stencil_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(0),
+                        store: wgpu::StoreOp::Discard,
+                    }),
+                }),
                 occlusion_query_set: None,
                 timestamp_writes: None,
             });
 
-            self.components.iter_mut().for_each(|component| {
-                component.render(&self.device, &self.config, &mut render_pass)
-            });
+            let order = self.draw_order(&visible);
+            let mut components: Vec<Option<&mut Comp>> =
+                self.components.iter_mut().map(Some).collect();
+            for id in order {
+                let (x, y, w, h) = clips[id];
+                render_pass.set_scissor_rect(x, y, w, h);
+                let component = components[id].take().unwrap();
+                component.render(device, &self.config, &mut render_pass);
+            }
+            // decorations on top of every component
+            render_pass.set_scissor_rect(full.0, full.1, full.2, full.3);
+            if drop_target.is_some() {
+                self.drop_highlight
+                    .render(device, &self.config, &mut render_pass);
+            }
+            if focus_ring.is_some() {
+                self.focus_ring
+                    .render(device, &self.config, &mut render_pass);
+            }
 
             drop(render_pass);
         }
 
-        self.queue.submit(once(encoder.finish()));
-        output.present();
+        queue.submit(once(encoder.finish()));
         self.components
             .iter_mut()
             .for_each(|component| component.clean());
-        Ok(())
     }
 
-    pub fn as_ctx(&mut self) -> Context {
+    /// the visible components in the order they are drawn, by id except for those drawing over
+    /// their children, which come right after their last visible descendant
+    pub(crate) fn draw_order(&self, visible: &[bool]) -> Vec<usize> {
+        let mut after = vec![vec![]; self.components.len()];
+        let mut order = vec![];
+        for id in (0..self.components.len()).filter(|id| visible[*id]) {
+            let last = match self.components[id].draws_over_children() {
+                true => self
+                    .descendants(id as isize)
+                    .iter()
+                    .map(|descendant| *descendant as usize)
+                    .filter(|descendant| visible[*descendant])
+                    .max()
+                    .filter(|last| *last > id),
+                false => None,
+            };
+            match last {
+                Some(last) => after[last].push(id),
+                None => order.push(id),
+            }
+        }
+        // ancestors come first by id, nested views go over their content before the outer ones
+        let mut drawn = vec![];
+        for id in order {
+            drawn.push(id);
+            drawn.extend(after[id].iter().rev());
+        }
+        drawn
+    }
+
+    /// swaps in the view `root`, keeping what the pointer, the keyboard and the animations were
+    /// doing to the components found at the same place in the new tree
+    fn replace_view(&mut self, root: Comp) {
+        let old_identities = self.identities();
+        let view = View::assemble(root, &self.config, self.gpu.as_ref());
+        let old = std::mem::replace(&mut self.components, view.components);
+        self.parents = view.parents;
+        self.tree_order = view.tree_order;
+        self.subtrees = view.subtrees;
+
+        let old_ids: HashMap<_, _> = old_identities
+            .into_iter()
+            .enumerate()
+            .map(|(id, identity)| (identity, id as isize))
+            .collect();
+        // the old id of every new component, and the new id of every old one
+        let origins: Vec<Option<isize>> = self
+            .identities()
+            .iter()
+            .map(|identity| old_ids.get(identity).copied())
+            .collect();
+        let mut successors = vec![None; old.len()];
+        for (id, origin) in origins.iter().enumerate() {
+            if let Some(origin) = origin {
+                successors[*origin as usize] = Some(id as isize);
+            }
+        }
+        let successor = |id: Option<isize>| id.and_then(|id| successors[id as usize]);
+        self.focused = successor(self.focused);
+        if let Some(focused) = self.focused {
+            self.components[focused as usize].set_focus(true);
+        }
+        self.hovered = successor(self.hovered);
+        // gestures in progress end with the view they started on
+        self.pressed.clear();
+        self.captured = None;
+        self.drag = None;
+        self.scrollbar_drag = None;
+        self.file_drop.target = None;
+        self.clicks = Clicks::default();
+        self.touches = Touches::default();
+        self.measured_layout.clear();
+        self.retarget_animations(&successors);
+        self.transition_view(&old, &origins);
+        self.request_redraw();
+    }
+
+    /// where each component sits in the tree, as the index among its siblings at every level
+    /// below the root, along with its kind, indexed by id
+    fn identities(&self) -> Vec<(Vec<usize>, Discriminant<Comp>)> {
+        let mut paths = vec![vec![]; self.components.len()];
+        let mut children = vec![0; self.components.len()];
+        for id in &self.tree_order {
+            if let Some(parent) = self.parents[*id as usize] {
+                let mut path = paths[parent as usize].clone();
+                path.push(children[parent as usize]);
+                children[parent as usize] += 1;
+                paths[*id as usize] = path;
+            }
+        }
+        paths
+            .into_iter()
+            .zip(self.components.iter())
+            .map(|(path, comp)| (path, discriminant(comp)))
+            .collect()
+    }
+
+    pub fn as_ctx(&mut self) -> Context<'_> {
         Context(self)
     }
 }
+
+/// The components of a view ordered by depth and their hierarchy, see the fields of [`State`].
+struct View {
+    components: Components,
+    parents: Vec<Option<isize>>,
+    tree_order: Vec<isize>,
+    subtrees: Vec<Range<usize>>,
+}
+
+impl View {
+    /// flattens the tree under `root` and gets every component ready for layout, and for
+    /// drawing with `gpu`
+    fn assemble(mut root: Comp, config: &wgpu::SurfaceConfiguration, gpu: Option<&Gpu>) -> Self {
+        let mut tree = root.collect();
+        let root_index = tree.len();
+        tree.push((root, None));
+        let mut order: Vec<usize> = (0..tree.len()).collect();
+        order.sort_by_key(|index| std::cmp::Reverse(tree[*index].0.depth()));
+        let mut ids = vec![0; tree.len()];
+        order
+            .iter()
+            .enumerate()
+            .for_each(|(id, index)| ids[*index] = id as isize);
+        let parents: Vec<Option<isize>> = order
+            .iter()
+            .map(|index| match tree[*index].1 {
+                Some(parent) => Some(ids[parent]),
+                None if *index == root_index => None,
+                None => Some(ids[root_index]),
+            })
+            .collect();
+        let tree_order: Vec<isize> = once(root_index)
+            .chain(0..root_index)
+            .map(|index| ids[index])
+            .collect();
+        let subtrees = subtrees(&tree_order, &parents);
+        let mut tree: Vec<_> = tree.into_iter().map(|(comp, _)| Some(comp)).collect();
+        let mut components: Components = order
+            .iter()
+            .map(|index| tree[*index].take().unwrap())
+            .collect();
+        components
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, comp)| comp.set_id(index as _));
+        components
+            .iter_mut()
+            .for_each(|comp| comp.init_layout(config));
+        if let Some(Gpu { device, queue, .. }) = gpu {
+            components
+                .iter_mut()
+                .for_each(|comp| comp.init(device, queue, config));
+        }
+        Self {
+            components,
+            parents,
+            tree_order,
+            subtrees,
+        }
+    }
+}
+
+/// the range of `tree_order` each component and its descendants follow, as a subtree is
+/// contiguous in pre-order
+fn subtrees(tree_order: &[isize], parents: &[Option<isize>]) -> Vec<Range<usize>> {
+    let mut levels = vec![0; parents.len()];
+    for id in tree_order {
+        if let Some(parent) = parents[*id as usize] {
+            levels[*id as usize] = levels[parent as usize] + 1;
+        }
+    }
+    let mut subtrees = vec![0..0; parents.len()];
+    for (position, id) in tree_order.iter().enumerate() {
+        let level = levels[*id as usize];
+        let len = tree_order[position + 1..]
+            .iter()
+            .take_while(|other| levels[**other as usize] > level)
+            .count();
+        subtrees[*id as usize] = position + 1..position + 1 + len;
+    }
+    subtrees
+}
@@ -0,0 +1,302 @@
+use std::time::Duration;
+
+use hierro2d::{
+    component::{Component, IntoComponent, Rect, Transform},
+    Animation, Application, Clock, Easing, Headless, ManualClock, Property, Spring, Timeline,
+    Tween,
+};
+
+struct App {
+    clock: ManualClock,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Rect::new().size(0.5, 0.5).color(0., 0., 0., 1.).opacity(1.)
+    }
+
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(self.clock.clone())
+    }
+}
+
+fn app() -> (Headless, ManualClock, isize) {
+    let clock = ManualClock::new();
+    let app = Headless::new(
+        App {
+            clock: clock.clone(),
+        },
+        200,
+        100,
+    );
+    let id = app.component_at((10., 10.)).unwrap();
+    (app, clock, id)
+}
+
+#[test]
+fn tween_follows_the_clock() {
+    let (mut app, clock, id) = app();
+    let tween = Tween::color(1., 1., 1., 1.)
+        .duration(Duration::from_millis(100))
+        .easing(Easing::Linear);
+    app.context().animate(id, tween);
+
+    app.update();
+    assert_eq!(app.component(id).get_color(), Some((0., 0., 0., 1.)));
+
+    clock.advance(Duration::from_millis(25));
+    app.update();
+    let (r, _, _, _) = app.component(id).get_color().unwrap();
+    assert!((r - 0.25).abs() < 1e-4, "{r}");
+    assert!(app.animating());
+
+    // nothing moves while the clock stands still
+    app.update();
+    assert_eq!(app.component(id).get_color().unwrap().0, r);
+
+    clock.advance(Duration::from_millis(100));
+    app.update();
+    assert_eq!(app.component(id).get_color(), Some((1., 1., 1., 1.)));
+    assert!(!app.animating());
+}
+
+/// opacity after every 16ms frame until the spring comes to rest
+fn spring_frames(spring: Spring) -> Vec<f32> {
+    let (mut app, clock, id) = app();
+    app.context().spring(id, spring);
+    let mut frames = vec![];
+    while app.animating() {
+        clock.advance(Duration::from_millis(16));
+        app.update();
+        frames.push(app.component(id).get_opacity().unwrap());
+        assert!(frames.len() < 1000, "the spring never came to rest");
+    }
+    frames
+}
+
+#[test]
+fn spring_settles_on_its_target() {
+    let frames = spring_frames(Spring::opacity(0.));
+    assert!(frames.windows(2).take(5).all(|pair| pair[1] < pair[0]));
+    assert_eq!(frames.last(), Some(&0.));
+    // the same steps of the clock give the same frames
+    assert_eq!(frames, spring_frames(Spring::opacity(0.)));
+}
+
+#[test]
+fn retargeted_spring_does_not_jump() {
+    let (mut app, clock, id) = app();
+    app.context().spring(id, Spring::opacity(0.));
+    for _ in 0..10 {
+        clock.advance(Duration::from_millis(16));
+        app.update();
+    }
+    let before = app.component(id).get_opacity().unwrap();
+    app.context().spring(id, Spring::opacity(1.));
+    clock.advance(Duration::from_millis(16));
+    app.update();
+    let after = app.component(id).get_opacity().unwrap();
+    // still moving down for a moment as the velocity carries over
+    assert!(after <= before && before - after < 0.1, "{before} {after}");
+    assert!(!app.context().stop_spring(id, Property::Color));
+    assert!(app.context().stop_spring(id, Property::Opacity));
+    assert!(!app.animating());
+}
+
+#[test]
+fn retargeted_spring_keeps_its_velocity() {
+    let (mut app, clock, id) = app();
+    let opacity = |app: &Headless| app.component(id).get_opacity().unwrap();
+    let frame = |app: &mut Headless| {
+        clock.advance(Duration::from_millis(16));
+        app.update();
+    };
+    app.context().spring(id, Spring::opacity(0.));
+    for _ in 0..5 {
+        frame(&mut app);
+    }
+    let before = opacity(&app);
+    frame(&mut app);
+    let speed = opacity(&app) - before;
+    assert!(speed < -0.05, "{speed}");
+
+    // the new target pulls the other way, but only changes the speed gradually
+    app.context().spring(id, Spring::opacity(1.));
+    let before = opacity(&app);
+    frame(&mut app);
+    let retargeted = opacity(&app) - before;
+    assert!(
+        retargeted < 0. && (retargeted - speed).abs() < 0.05,
+        "{speed} {retargeted}"
+    );
+}
+
+#[test]
+fn massless_spring_still_settles() {
+    let frames = spring_frames(Spring::opacity(0.).mass(0.));
+    assert!(frames.iter().all(|opacity| opacity.is_finite()));
+    assert_eq!(frames.last(), Some(&0.));
+    let frames = spring_frames(Spring::opacity(0.).mass(-1.).damping(200.));
+    assert_eq!(frames.last(), Some(&0.));
+}
+
+#[test]
+fn setters_ignore_unknown_ids() {
+    let (mut app, _, id) = app();
+    let mut context = app.context();
+    for stale in [-1, 1, 100] {
+        context.set_position(stale, 0.5, 0.5);
+        context.set_size(stale, 0.5, 0.5);
+        context.set_color(stale, 1., 1., 1., 1.);
+        context.set_radius(stale, 4.);
+        context.set_opacity(stale, 0.5);
+        context.set_transform(stale, Transform::new().rotate(1.));
+        context.translate(stale, (10., 10.));
+    }
+    assert_eq!(app.component(id).get_color(), Some((0., 0., 0., 1.)));
+}
+
+const STEP: Duration = Duration::from_millis(50);
+
+fn fade() -> Tween {
+    Tween::opacity(0.)
+        .duration(Duration::from_millis(100))
+        .easing(Easing::Linear)
+}
+
+fn whiten(millis: u64) -> Tween {
+    Tween::color(1., 1., 1., 1.)
+        .duration(Duration::from_millis(millis))
+        .easing(Easing::Linear)
+}
+
+/// the opacity and the red channel, rounded, after every step of 50ms until the animation is over
+fn frames(animation: impl Into<Animation>) -> Vec<(f32, f32)> {
+    let (mut app, clock, id) = app();
+    app.context().animate(id, animation);
+    let mut frames = vec![];
+    while app.animating() {
+        clock.advance(STEP);
+        app.update();
+        let comp = app.component(id);
+        let round = |value: f32| (value * 1000.).round() / 1000.;
+        frames.push((
+            round(comp.get_opacity().unwrap()),
+            round(comp.get_color().unwrap().0),
+        ));
+        assert!(frames.len() < 100, "the animation never finished");
+    }
+    frames
+}
+
+#[test]
+fn sequence_plays_its_steps_one_after_another() {
+    let sequence = Timeline::sequence([
+        fade().into(),
+        Timeline::delay(Duration::from_millis(100)),
+        whiten(100).into(),
+    ]);
+    assert_eq!(
+        frames(sequence),
+        [(0.5, 0.), (0., 0.), (0., 0.), (0., 0.), (0., 0.5), (0., 1.)]
+    );
+}
+
+#[test]
+fn sequence_of_one_property_continues_from_the_previous_step() {
+    let sequence = Timeline::sequence([
+        fade(),
+        Tween::opacity(1.)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear),
+    ]);
+    assert_eq!(frames(sequence), [(0.5, 0.), (0., 0.), (0.5, 0.), (1., 0.)]);
+}
+
+#[test]
+fn parallel_plays_its_steps_together_for_the_longest() {
+    let parallel = Timeline::parallel([fade(), whiten(200)]);
+    assert_eq!(
+        frames(parallel),
+        [(0.5, 0.25), (0., 0.5), (0., 0.75), (0., 1.)]
+    );
+}
+
+#[test]
+fn repeat_restarts_from_the_first_value() {
+    let frames = frames(Animation::new(fade()).repeat(3));
+    assert_eq!(
+        frames,
+        [
+            (0.5, 0.),
+            (1., 0.),
+            (0.5, 0.),
+            (1., 0.),
+            (0.5, 0.),
+            (0., 0.)
+        ]
+    );
+}
+
+#[test]
+fn alternate_plays_every_other_repetition_backwards() {
+    let frames = frames(Animation::new(fade()).repeat(3).alternate());
+    assert_eq!(
+        frames,
+        [
+            (0.5, 0.),
+            (0., 0.),
+            (0.5, 0.),
+            (1., 0.),
+            (0.5, 0.),
+            (0., 0.)
+        ]
+    );
+}
+
+#[test]
+fn alternate_rewinds_each_step_of_a_sequence() {
+    let sequence = Timeline::sequence([
+        Timeline::delay(Duration::from_millis(25)),
+        fade().into(),
+        whiten(100).into(),
+    ]);
+    let frames = frames(Animation::new(sequence).repeat(2).alternate());
+    assert_eq!(
+        frames,
+        [
+            (0.75, 0.),
+            (0.25, 0.),
+            (0., 0.25),
+            (0., 0.75),
+            (0., 0.75),
+            (0., 0.25),
+            // the color is back where it started before the fade rewinds
+            (0.25, 0.),
+            (0.75, 0.),
+            (1., 0.)
+        ]
+    );
+}
+
+#[test]
+fn forever_runs_until_cancelled() {
+    let (mut app, clock, id) = app();
+    let animation = app
+        .context()
+        .animate(id, Animation::new(fade()).forever().alternate());
+    for _ in 0..100 {
+        clock.advance(STEP);
+        app.update();
+    }
+    assert!(app.context().is_animating(animation));
+    assert_eq!(app.component(id).get_opacity(), Some(1.));
+
+    clock.advance(STEP);
+    app.update();
+    assert!(app.context().cancel_animation(animation));
+    assert!(!app.animating());
+    clock.advance(STEP);
+    app.update();
+    assert_eq!(app.component(id).get_opacity(), Some(0.5));
+}
@@ -0,0 +1,82 @@
+use std::{cell::Cell, rc::Rc};
+
+use hierro2d::{
+    component::{IntoComponent, Rect},
+    Application, Context, Headless, Shortcut, Shortcuts,
+};
+use winit::keyboard::{Key, NamedKey};
+
+/// refuses to close while there are unsaved changes, and quits with Escape
+struct App {
+    unsaved: Rc<Cell<bool>>,
+    asked: Rc<Cell<usize>>,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Rect::new()
+    }
+
+    fn shortcuts(&self, shortcuts: &mut Shortcuts) {
+        shortcuts
+            .register(Shortcut::new(Key::Named(NamedKey::Escape)), |mut ctx| {
+                ctx.exit()
+            })
+            .unwrap();
+    }
+
+    fn on_close_requested(&self) -> impl FnMut(Context) -> bool + 'static {
+        let (unsaved, asked) = (self.unsaved.clone(), self.asked.clone());
+        move |_| {
+            asked.set(asked.get() + 1);
+            !unsaved.get()
+        }
+    }
+}
+
+fn app(unsaved: bool) -> (Headless, Rc<Cell<bool>>, Rc<Cell<usize>>) {
+    let (unsaved, asked) = (Rc::new(Cell::new(unsaved)), Rc::new(Cell::new(0)));
+    let app = Headless::new(
+        App {
+            unsaved: unsaved.clone(),
+            asked: asked.clone(),
+        },
+        100,
+        100,
+    );
+    (app, unsaved, asked)
+}
+
+#[test]
+fn vetoed_close_keeps_the_app_running() {
+    let (mut app, unsaved, asked) = app(true);
+    app.request_close();
+    assert_eq!(asked.get(), 1);
+    assert!(!app.exit_requested());
+
+    unsaved.set(false);
+    app.request_close();
+    assert_eq!(asked.get(), 2);
+    assert!(app.exit_requested());
+}
+
+#[test]
+fn close_is_allowed_by_default() {
+    struct Plain;
+    impl Application for Plain {
+        fn view(self) -> impl IntoComponent {
+            Rect::new()
+        }
+    }
+    let mut app = Headless::new(Plain, 100, 100);
+    app.request_close();
+    assert!(app.exit_requested());
+}
+
+#[test]
+fn exit_skips_the_close_handler() {
+    let (mut app, _, asked) = app(true);
+    app.tap(Key::Named(NamedKey::Escape));
+    assert!(app.exit_requested());
+    assert_eq!(asked.get(), 0);
+}
@@ -0,0 +1,115 @@
+use std::{cell::RefCell, rc::Rc};
+
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect},
+    Application, Context, Headless, Phase, PointerEvent,
+};
+use winit::event::MouseButton;
+
+type Log = Rc<RefCell<Vec<(&'static str, &'static str, Phase)>>>;
+
+/// a root, a middle and a leaf rect nested in the top left corner, each recording its capture and
+/// mouse down handlers, with propagation stopped by the handler named in `stop`
+struct App {
+    log: Log,
+    stop: Option<(&'static str, &'static str)>,
+}
+
+fn record(
+    rect: Rect,
+    name: &'static str,
+    log: &Log,
+    stop: Option<(&'static str, &'static str)>,
+) -> Rect {
+    let recorder = |handler: &'static str| {
+        let log = log.clone();
+        move |_: Context, event: &mut PointerEvent| {
+            log.borrow_mut().push((name, handler, event.phase));
+            if stop == Some((name, handler)) {
+                event.stop_propagation();
+            }
+        }
+    };
+    rect.on_pointer_capture(recorder("capture"))
+        .on_mouse_down(recorder("down"))
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let leaf = record(Rect::new().size(0.5, 0.5), "leaf", &self.log, self.stop);
+        let middle = record(Rect::new().size(0.5, 0.5), "middle", &self.log, self.stop);
+        let root = record(Rect::new().size(1., 1.), "root", &self.log, self.stop);
+        root.with_child(middle.with_child(leaf))
+    }
+}
+
+fn press(stop: Option<(&'static str, &'static str)>) -> Vec<(&'static str, &'static str, Phase)> {
+    let log = Log::default();
+    let mut app = Headless::new(
+        App {
+            log: log.clone(),
+            stop,
+        },
+        100,
+        100,
+    );
+    app.move_to((10., 10.));
+    // the move is delivered to the capture handlers too, keep only the press
+    log.borrow_mut().clear();
+    app.press(MouseButton::Left);
+    log.take()
+}
+
+#[test]
+fn capture_runs_down_the_path_then_bubbles_up() {
+    use Phase::*;
+    assert_eq!(
+        press(None),
+        [
+            ("root", "capture", Capture),
+            ("middle", "capture", Capture),
+            ("leaf", "capture", Target),
+            ("leaf", "down", Target),
+            ("middle", "down", Bubble),
+            ("root", "down", Bubble),
+        ]
+    );
+}
+
+#[test]
+fn stopping_in_capture_skips_the_target_and_the_bubble() {
+    use Phase::*;
+    assert_eq!(
+        press(Some(("middle", "capture"))),
+        [("root", "capture", Capture), ("middle", "capture", Capture)]
+    );
+}
+
+#[test]
+fn stopping_at_the_target_skips_the_bubble() {
+    use Phase::*;
+    assert_eq!(
+        press(Some(("leaf", "down"))),
+        [
+            ("root", "capture", Capture),
+            ("middle", "capture", Capture),
+            ("leaf", "capture", Target),
+            ("leaf", "down", Target),
+        ]
+    );
+}
+
+#[test]
+fn stopping_in_the_bubble_skips_the_remaining_ancestors() {
+    use Phase::*;
+    assert_eq!(
+        press(Some(("middle", "down"))),
+        [
+            ("root", "capture", Capture),
+            ("middle", "capture", Capture),
+            ("leaf", "capture", Target),
+            ("leaf", "down", Target),
+            ("middle", "down", Bubble),
+        ]
+    );
+}
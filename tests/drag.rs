@@ -0,0 +1,71 @@
+use std::{cell::RefCell, rc::Rc};
+
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect},
+    Application, DragKind, Headless,
+};
+use winit::event::MouseButton;
+
+type Drops = Rc<RefCell<Vec<(DragKind, isize, Option<isize>)>>>;
+
+struct App {
+    drops: Drops,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let drops = self.drops;
+        let slot = Rect::new()
+            .position(0.5, 0.)
+            .size(0.5, 1.)
+            .on_drop(move |ctx, event| {
+                let id = ctx.current_id();
+                drops.borrow_mut().push((event.kind, event.source, id));
+            });
+        // added after the slot, the card is drawn over it while dragged there
+        let card = Rect::new().size(0.25, 0.5).draggable();
+        Rect::new().with_child(slot).with_child(card)
+    }
+}
+
+fn drag(app: &mut Headless, from: (f32, f32), to: (f32, f32)) {
+    app.move_to(from);
+    app.press(MouseButton::Left);
+    app.move_to((from.0 + 10., from.1));
+    app.move_to(to);
+    app.release(MouseButton::Left);
+}
+
+#[test]
+fn drops_onto_a_sibling_under_the_dragged_component() {
+    let drops = Drops::default();
+    let mut app = Headless::new(
+        App {
+            drops: drops.clone(),
+        },
+        200,
+        100,
+    );
+    let card = app.component_at((10., 10.)).unwrap();
+    let slot = app.component_at((150., 50.)).unwrap();
+    assert_ne!(card, slot);
+
+    drag(&mut app, (10., 10.), (150., 30.));
+    assert_eq!(*drops.borrow(), [(DragKind::Drop, card, Some(slot))]);
+    // the card returns to where it was picked up, the handler decides where it goes
+    assert_eq!(app.component_at((10., 10.)), Some(card));
+}
+
+#[test]
+fn no_drop_outside_of_targets() {
+    let drops = Drops::default();
+    let mut app = Headless::new(
+        App {
+            drops: drops.clone(),
+        },
+        200,
+        100,
+    );
+    drag(&mut app, (10., 10.), (60., 80.));
+    assert!(drops.borrow().is_empty());
+}
@@ -0,0 +1,107 @@
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect},
+    Application, FileDropEvent, Headless,
+};
+
+type Log = Rc<RefCell<Vec<FileDropEvent>>>;
+
+/// a drop zone over the left half with a child that takes no files, and a plain rect over the
+/// right half
+struct App {
+    log: Log,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let log = self.log;
+        Rect::new()
+            .size(1., 1.)
+            .with_child(
+                Rect::new()
+                    .size(0.5, 1.)
+                    .on_file_drop(move |_, event| log.borrow_mut().push(event.clone()))
+                    .with_child(Rect::new().position(0., 0.5).size(1., 0.5)),
+            )
+            .with_child(Rect::new().position(0.5, 0.).size(0.5, 1.))
+    }
+}
+
+fn app() -> (Headless, Log) {
+    let log = Log::default();
+    let app = Headless::new(App { log: log.clone() }, 200, 100);
+    (app, log)
+}
+
+fn paths(names: &[&str]) -> Vec<PathBuf> {
+    names.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn files_are_dropped_together_on_the_zone() {
+    let (mut app, log) = app();
+    let zone = app.component_at((50., 20.)).unwrap();
+    app.hover_file("a.png", (50., 20.));
+    app.hover_file("b.png", (50., 20.));
+    app.drop_files(["a.png", "b.png"]);
+
+    let log = log.borrow();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].paths, paths(&["a.png", "b.png"]));
+    assert_eq!(log[0].target, zone);
+    assert_eq!(log[0].position, (50., 20.));
+    assert_eq!(log[0].local, (50., 20.));
+}
+
+#[test]
+fn drop_lands_where_the_files_hovered_not_at_the_stale_pointer() {
+    let (mut app, log) = app();
+    // the system drag takes over the pointer, which was last seen over the right half
+    app.move_to((150., 50.));
+    app.hover_file("a.png", (40., 30.));
+    app.drop_files(["a.png"]);
+
+    let log = log.borrow();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].position, (40., 30.));
+}
+
+#[test]
+fn drop_on_a_child_goes_to_the_nearest_zone() {
+    let (mut app, log) = app();
+    let zone = app.component_at((50., 20.)).unwrap();
+    assert_ne!(app.component_at((50., 80.)), Some(zone));
+    app.hover_file("a.png", (50., 80.));
+    app.drop_files(["a.png"]);
+
+    let log = log.borrow();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].target, zone);
+    assert_eq!(log[0].local, (50., 80.));
+}
+
+#[test]
+fn drop_outside_any_zone_is_ignored() {
+    let (mut app, log) = app();
+    app.hover_file("a.png", (150., 50.));
+    app.drop_files(["a.png"]);
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn cancelled_hover_forgets_its_position() {
+    let (mut app, log) = app();
+    app.hover_file("a.png", (50., 20.));
+    app.cancel_file_hover();
+    // without a hover the drop falls back to the pointer
+    app.move_to((150., 50.));
+    app.drop_files(["a.png"]);
+    assert!(log.borrow().is_empty());
+
+    app.move_to((60., 10.));
+    app.drop_files(["a.png"]);
+    let log = log.borrow();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].position, (60., 10.));
+}
@@ -0,0 +1,80 @@
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect},
+    Application, Headless,
+};
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+/// five slots in a row, with only the first and the last focusable and shown: the second is
+/// hidden, the third is not focusable and the fourth sits in a hidden parent
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let slot = |index: usize| Rect::new().position(index as f32 * 0.2, 0.).size(0.2, 1.);
+        Rect::new()
+            .size(1., 1.)
+            .with_child(slot(0).focusable(true))
+            .with_child(slot(1).focusable(true).visible(false))
+            .with_child(slot(2))
+            .with_child(
+                slot(3)
+                    .visible(false)
+                    .with_child(Rect::new().size(1., 1.).focusable(true)),
+            )
+            .with_child(slot(4).focusable(true))
+    }
+}
+
+fn app() -> (Headless, isize, isize) {
+    let app = Headless::new(App, 500, 100);
+    let first = app.component_at((50., 50.)).unwrap();
+    let last = app.component_at((450., 50.)).unwrap();
+    (app, first, last)
+}
+
+const TAB: Key = Key::Named(NamedKey::Tab);
+
+#[test]
+fn tab_skips_hidden_and_unfocusable_components_and_wraps() {
+    let (mut app, first, last) = app();
+    assert_eq!(app.focused(), None);
+    app.tap(TAB);
+    assert_eq!(app.focused(), Some(first));
+    app.tap(TAB);
+    assert_eq!(app.focused(), Some(last));
+    app.tap(TAB);
+    assert_eq!(app.focused(), Some(first));
+}
+
+#[test]
+fn shift_tab_goes_backwards_and_wraps() {
+    let (mut app, first, last) = app();
+    app.set_modifiers(ModifiersState::SHIFT);
+    app.tap(TAB);
+    assert_eq!(app.focused(), Some(last));
+    app.tap(TAB);
+    assert_eq!(app.focused(), Some(first));
+    app.tap(TAB);
+    assert_eq!(app.focused(), Some(last));
+}
+
+#[test]
+fn alt_tab_leaves_the_focus_alone() {
+    let (mut app, _, _) = app();
+    app.set_modifiers(ModifiersState::ALT);
+    app.tap(TAB);
+    assert_eq!(app.focused(), None);
+}
+
+#[test]
+fn context_moves_the_focus_like_tab() {
+    let (mut app, first, last) = app();
+    app.context().focus_next();
+    assert_eq!(app.focused(), Some(first));
+    app.context().focus_next();
+    assert_eq!(app.focused(), Some(last));
+    app.context().focus_previous();
+    assert_eq!(app.focused(), Some(first));
+    app.context().focus_previous();
+    assert_eq!(app.focused(), Some(last));
+}
@@ -0,0 +1,145 @@
+use hierro2d::{
+    component::{BorderAlign, Comp, Component, Container, Img, IntoComponent, Overflow, Rect},
+    Application, Headless,
+};
+
+const BADGE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/badge.png");
+
+enum Scene {
+    /// a hidden rect over the right half of a visible one
+    Hidden,
+    /// a hidden rect with a visible child over its right half
+    HiddenParent,
+    /// a rect over the left half cutting off a child twice as wide
+    Clipped,
+    /// a disc with transparent corners filling the window
+    Badge { ignore_transparent: bool },
+    /// a rect filling the window with corners of 40, 10, 0 and 50 pixels from the top left
+    Corners,
+    /// a rect from 25 to 75 pixels on both axes with a 10 pixel border
+    Border(BorderAlign),
+}
+
+impl Application for Scene {
+    fn view(self) -> impl IntoComponent {
+        let right = || Rect::new().position(0.5, 0.).size(0.5, 1.);
+        match self {
+            Scene::Hidden => Rect::new()
+                .size(1., 1.)
+                .with_child(right().visible(false))
+                .into_comp(),
+            Scene::HiddenParent => Rect::new()
+                .size(1., 1.)
+                .visible(false)
+                .with_child(right())
+                .into_comp(),
+            Scene::Clipped => Rect::new()
+                .size(0.5, 1.)
+                .overflow(Overflow::Clip)
+                .with_child(Rect::new().size(2., 1.))
+                .into_comp(),
+            Scene::Badge { ignore_transparent } => Img::new()
+                .content(BADGE)
+                .unwrap()
+                .size(1., 1.)
+                .ignore_transparent(ignore_transparent)
+                .into_comp(),
+            // radii are in halves of the window height, 50 pixels here
+            Scene::Corners => Rect::new()
+                .size(1., 1.)
+                .corner_radii(0.8, 0.2, 0., 1.)
+                .into_comp(),
+            Scene::Border(align) => Rect::new()
+                .position(0.25, 0.25)
+                .size(0.5, 0.5)
+                .border(10.)
+                .border_align(align)
+                .into_comp(),
+        }
+    }
+}
+
+fn app(scene: Scene) -> Headless {
+    Headless::new(scene, 100, 100)
+}
+
+fn is_rect(app: &Headless, id: isize) -> bool {
+    matches!(app.component(id), Comp::Rect(_))
+}
+
+#[test]
+fn hidden_components_are_not_hit() {
+    let app = app(Scene::Hidden);
+    let left = app.component_at((25., 50.)).unwrap();
+    assert!(is_rect(&app, left));
+    // the hidden child lets the pointer through to its parent
+    assert_eq!(app.component_at((75., 50.)), Some(left));
+}
+
+#[test]
+fn children_of_hidden_components_are_not_hit() {
+    let app = app(Scene::HiddenParent);
+    assert_eq!(app.component_at((25., 50.)), None);
+    assert_eq!(app.component_at((75., 50.)), None);
+}
+
+#[test]
+fn clipped_parts_are_not_hit() {
+    let app = app(Scene::Clipped);
+    let child = app.component_at((25., 50.)).unwrap();
+    assert!(is_rect(&app, child));
+    assert_eq!(app.component_at((75., 50.)), None);
+}
+
+#[test]
+fn transparent_pixels_are_hit_only_when_not_ignored() {
+    let ignoring = app(Scene::Badge {
+        ignore_transparent: true,
+    });
+    assert!(ignoring.component_at((50., 50.)).is_some());
+    assert_eq!(ignoring.component_at((2., 2.)), None);
+
+    let plain = app(Scene::Badge {
+        ignore_transparent: false,
+    });
+    assert!(plain.component_at((50., 50.)).is_some());
+    assert!(plain.component_at((2., 2.)).is_some());
+}
+
+#[test]
+fn each_corner_is_cut_by_its_own_radius() {
+    let app = app(Scene::Corners);
+    let hit = |point| app.component_at(point).is_some();
+    // top left, 40 pixels around (40, 40)
+    assert!(!hit((8., 8.)));
+    assert!(hit((15., 15.)));
+    // top right, 10 pixels around (90, 10), inside where the top left radius would cut
+    assert!(!hit((98., 2.)));
+    assert!(hit((95., 5.)));
+    // bottom right, square
+    assert!(hit((99., 99.)));
+    // bottom left, 50 pixels around (50, 50)
+    assert!(!hit((10., 90.)));
+    assert!(hit((20., 80.)));
+}
+
+#[test]
+fn border_alignment_moves_the_hit_edge() {
+    let hits = |align, x| app(Scene::Border(align)).component_at((x, 50.)).is_some();
+    // inside the bounds, the edge stays at 25 pixels
+    assert!(hits(BorderAlign::Inner, 27.));
+    assert!(!hits(BorderAlign::Inner, 22.));
+    // across the edge, half the border sticks out
+    assert!(hits(BorderAlign::Center, 22.));
+    assert!(!hits(BorderAlign::Center, 18.));
+    // around the bounds, the whole border sticks out
+    assert!(hits(BorderAlign::Outer, 18.));
+    assert!(!hits(BorderAlign::Outer, 13.));
+}
+
+#[test]
+fn undecodable_content_is_an_error() {
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    assert!(Img::new().content(manifest).is_err());
+    assert!(Img::new().content("missing.png").is_err());
+}
@@ -0,0 +1,80 @@
+use hierro2d::{
+    component::{Comp, IntoComponent, TextInput},
+    Application, Headless,
+};
+use winit::{
+    event::Ime,
+    keyboard::{Key, NamedKey},
+};
+
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        TextInput::new().content("ab").size(1., 1.)
+    }
+}
+
+/// the application with the input focused and its cursor after "ab"
+fn app() -> (Headless, isize) {
+    let mut app = Headless::new(App, 200, 100);
+    app.click((50., 50.));
+    let id = app.focused().expect("the input takes the focus");
+    (app, id)
+}
+
+fn value(app: &Headless, id: isize) -> &str {
+    match app.component(id) {
+        Comp::TextInput(input) => input.value(),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn preedit_is_committed_at_the_cursor() {
+    let (mut app, id) = app();
+    app.tap(Key::Named(NamedKey::ArrowLeft));
+
+    app.ime(Ime::Enabled);
+    app.ime(Ime::Preedit("n".into(), Some((1, 1))));
+    assert_eq!(value(&app, id), "ab");
+    app.ime(Ime::Preedit("ni".into(), Some((2, 2))));
+    assert_eq!(value(&app, id), "ab");
+
+    app.ime(Ime::Commit("你".into()));
+    assert_eq!(value(&app, id), "a你b");
+    // the cursor follows the committed text
+    app.tap(Key::Character("c".into()));
+    assert_eq!(value(&app, id), "a你cb");
+}
+
+#[test]
+fn keys_belong_to_the_input_method_while_composing() {
+    let (mut app, id) = app();
+    app.ime(Ime::Preedit("ni".into(), Some((2, 2))));
+    app.tap(Key::Named(NamedKey::Backspace));
+    app.tap(Key::Character("x".into()));
+    assert_eq!(value(&app, id), "ab");
+
+    app.ime(Ime::Commit("你".into()));
+    app.tap(Key::Named(NamedKey::Backspace));
+    assert_eq!(value(&app, id), "ab");
+}
+
+#[test]
+fn cancelled_preedit_leaves_the_value_alone() {
+    let (mut app, id) = app();
+    // an empty preedit ends the composition without committing anything
+    app.ime(Ime::Preedit("ni".into(), Some((2, 2))));
+    app.ime(Ime::Preedit(String::new(), None));
+    assert_eq!(value(&app, id), "ab");
+    app.tap(Key::Named(NamedKey::Backspace));
+    assert_eq!(value(&app, id), "a");
+
+    // and so does turning the input method off
+    app.ime(Ime::Preedit("ni".into(), Some((2, 2))));
+    app.ime(Ime::Disabled);
+    assert_eq!(value(&app, id), "a");
+    app.tap(Key::Character("c".into()));
+    assert_eq!(value(&app, id), "ac");
+}
@@ -0,0 +1,42 @@
+use hierro2d::{
+    component::{IntoComponent, Path},
+    Application, Headless,
+};
+
+struct App(Path);
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        self.0
+    }
+}
+
+/// a horizontal zigzag through the middle of a 1000 by 1000 view box with `points` corners
+fn zigzag(points: u32) -> Path {
+    let mut path = Path::new().view_box(0., 0., 1000., 1000.).move_to(0., 500.);
+    for i in 1..points {
+        let y = match i % 2 {
+            0 => 499.,
+            _ => 501.,
+        };
+        path = path.line_to(i as f32 * 1000. / points as f32, y);
+    }
+    path.color(0., 0., 0., 0.)
+        .stroke(10.)
+        .stroke_color(1., 1., 1., 1.)
+}
+
+#[test]
+fn large_paths_are_not_dropped() {
+    // far more vertices than 16 bit indices can address
+    let app = Headless::new(App(zigzag(40_000)), 400, 400);
+    assert!(app.component_at((200., 200.)).is_some());
+    assert!(app.component_at((200., 100.)).is_none());
+}
+
+#[test]
+fn tiny_dashes_finish() {
+    let path = zigzag(4).dashes(&[1e-6, 1e-6]);
+    let app = Headless::new(App(path), 400, 400);
+    assert!((0..400).any(|x| app.component_at((x as f32, 200.)).is_some()));
+}
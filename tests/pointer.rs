@@ -0,0 +1,127 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use hierro2d::{
+    component::{Component, IntoComponent, Rect},
+    Application, Clock, Headless, ManualClock, PointerKind,
+};
+use winit::event::MouseButton;
+
+type Log = Rc<RefCell<Vec<PointerKind>>>;
+
+struct App {
+    clock: ManualClock,
+    log: Log,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let log = self.log;
+        let (click, long_press) = (log.clone(), log);
+        Rect::new()
+            .size(0.5, 0.5)
+            .on_click(move |_, event| click.borrow_mut().push(event.kind))
+            .on_long_press(move |_, event| long_press.borrow_mut().push(event.kind))
+    }
+
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(self.clock.clone())
+    }
+}
+
+fn app() -> (Headless, ManualClock, Log) {
+    let clock = ManualClock::new();
+    let log = Log::default();
+    let app = Headless::new(
+        App {
+            clock: clock.clone(),
+            log: log.clone(),
+        },
+        200,
+        100,
+    );
+    (app, clock, log)
+}
+
+#[test]
+fn long_press_fires_after_the_configured_time() {
+    let (mut app, clock, log) = app();
+    app.move_to((10., 10.));
+    app.press(MouseButton::Left);
+    assert_eq!(app.next_timeout(), Some(Duration::from_millis(500)));
+
+    clock.advance(Duration::from_millis(499));
+    app.update();
+    assert!(log.borrow().is_empty());
+    assert_eq!(app.next_timeout(), Some(Duration::from_millis(1)));
+
+    clock.advance(Duration::from_millis(1));
+    app.update();
+    assert_eq!(*log.borrow(), [PointerKind::LongPress]);
+    assert_eq!(app.next_timeout(), None);
+
+    // the release that ends a long press is not a click
+    app.release(MouseButton::Left);
+    assert_eq!(*log.borrow(), [PointerKind::LongPress]);
+}
+
+#[test]
+fn short_press_clicks() {
+    let (mut app, clock, log) = app();
+    app.move_to((10., 10.));
+    app.press(MouseButton::Left);
+    clock.advance(Duration::from_millis(200));
+    app.update();
+    app.release(MouseButton::Left);
+    assert_eq!(*log.borrow(), [PointerKind::Click]);
+    assert_eq!(app.next_timeout(), None);
+}
+
+#[test]
+fn moving_away_cancels_the_long_press() {
+    let (mut app, clock, log) = app();
+    app.move_to((10., 10.));
+    app.press(MouseButton::Left);
+    app.move_to((30., 10.));
+    clock.advance(Duration::from_secs(1));
+    app.update();
+    assert!(log.borrow().is_empty());
+}
+
+struct Plain {
+    clock: ManualClock,
+    log: Log,
+}
+
+impl Application for Plain {
+    fn view(self) -> impl IntoComponent {
+        let log = self.log;
+        Rect::new()
+            .size(0.5, 0.5)
+            .on_click(move |_, event| log.borrow_mut().push(event.kind))
+    }
+
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(self.clock.clone())
+    }
+}
+
+#[test]
+fn held_press_without_long_press_handler_still_clicks() {
+    let clock = ManualClock::new();
+    let log = Log::default();
+    let mut app = Headless::new(
+        Plain {
+            clock: clock.clone(),
+            log: log.clone(),
+        },
+        200,
+        100,
+    );
+    app.move_to((10., 10.));
+    app.press(MouseButton::Left);
+    assert_eq!(app.next_timeout(), None);
+    clock.advance(Duration::from_secs(2));
+    app.update();
+    app.release(MouseButton::Left);
+    assert_eq!(*log.borrow(), [PointerKind::Click]);
+}
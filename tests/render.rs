@@ -0,0 +1,56 @@
+use hierro2d::{
+    component::{Component, Container, IntoComponent, Rect, Transform},
+    Application, Headless,
+};
+
+const RED: [u8; 3] = [255, 0, 0];
+
+struct Mirrored;
+
+impl Application for Mirrored {
+    fn view(self) -> impl IntoComponent {
+        // the left half, mirrored over its right edge onto the right half
+        Rect::new()
+            .size(0.5, 1.)
+            .color(1., 0., 0., 1.)
+            .transform(Transform::new().scale(-1., 1.).origin(1., 0.5))
+    }
+}
+
+#[tokio::test]
+async fn mirrored_rect_is_drawn_and_hit() {
+    let Some(mut app) = Headless::with_gpu(Mirrored, 200, 100).await else {
+        eprintln!("no adapter to draw with, skipping");
+        return;
+    };
+    let pixels = app.render().unwrap();
+    let pixel = |x: usize, y: usize| pixels[(y * 200 + x) * 4..][..3].to_vec();
+    assert_eq!(pixel(150, 50), RED);
+    assert_ne!(pixel(50, 50), RED);
+    assert!(app.component_at((150., 50.)).is_some());
+    assert!(app.component_at((50., 50.)).is_none());
+}
+
+struct Translucent;
+
+impl Application for Translucent {
+    fn view(self) -> impl IntoComponent {
+        Rect::new()
+            .size(1., 1.)
+            .color(1., 0., 0., 1.)
+            .with_child(Rect::new().size(1., 1.).color(0., 0., 1., 0.5))
+    }
+}
+
+#[tokio::test]
+async fn translucent_fill_shows_what_is_behind() {
+    let Some(mut app) = Headless::with_gpu(Translucent, 100, 100).await else {
+        eprintln!("no adapter to draw with, skipping");
+        return;
+    };
+    let pixels = app.render().unwrap();
+    let [r, g, b] = [pixels[0], pixels[1], pixels[2]];
+    assert!(r > 100 && r < 255, "{r}");
+    assert_eq!(g, 0);
+    assert!(b > 100 && b < 255, "{b}");
+}
@@ -0,0 +1,110 @@
+use hierro2d::{
+    component::{Comp, Container, IntoComponent, Overflow, Rect, ScrollView},
+    Application, Headless,
+};
+use winit::event::MouseButton;
+
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        ScrollView::new()
+            .size(1., 0.5)
+            .with_child(Rect::new().size(1., 2.).color(1., 1., 1., 1.))
+    }
+}
+
+const VIEW: isize = 0;
+
+/// the furthest the view scrolls down
+fn max_scroll(app: &mut Headless) -> f32 {
+    let mut context = app.context();
+    context.scroll_to(VIEW, (0., f32::MAX));
+    let offset = context.scroll_offset(VIEW).unwrap().1;
+    context.scroll_to(VIEW, (0., 0.));
+    offset
+}
+
+#[test]
+fn content_is_measured_again_after_a_layout_change() {
+    let mut app = Headless::new(App, 200, 200);
+    assert_eq!(max_scroll(&mut app), 100.);
+    // scrolling moves the content but does not change its size
+    assert_eq!(max_scroll(&mut app), 100.);
+
+    let child = app.component_at((10., 10.)).unwrap();
+    assert_ne!(child, VIEW);
+    app.context().set_size(child, 1., 2.);
+    assert_eq!(max_scroll(&mut app), 300.);
+}
+
+/// a scroll view 200 by 100 pixels in a 200 by 200 window with its vertical thumb at the top of
+/// the right edge
+fn list() -> ScrollView {
+    ScrollView::new()
+        .size(1., 0.5)
+        .with_child(Rect::new().size(1., 2.).color(1., 1., 1., 1.))
+}
+
+enum Thumb {
+    Free,
+    /// a sibling drawn over the top right corner
+    Covered,
+    /// inside a parent that cuts off the right half
+    Clipped,
+}
+
+impl Application for Thumb {
+    fn view(self) -> impl IntoComponent {
+        match self {
+            Thumb::Free => Rect::new().size(1., 1.).with_child(list()),
+            Thumb::Covered => Rect::new().size(1., 1.).with_child(list()).with_child(
+                Rect::new()
+                    .position(0.8, 0.)
+                    .size(0.2, 0.25)
+                    .color(1., 0., 0., 1.),
+            ),
+            Thumb::Clipped => Rect::new()
+                .size(0.5, 1.)
+                .overflow(Overflow::Clip)
+                .with_child(
+                    ScrollView::new()
+                        .size(2., 0.5)
+                        .with_child(Rect::new().size(1., 2.).color(1., 1., 1., 1.)),
+                ),
+        }
+    }
+}
+
+/// drags from where the thumb sits and returns how far that scrolled the view
+fn drag_thumb(app: &mut Headless) -> f32 {
+    let view = (0..)
+        .find(|id| matches!(app.component(*id), Comp::ScrollView(_)))
+        .unwrap();
+    app.move_to((194., 10.));
+    app.press(MouseButton::Left);
+    app.move_to((194., 40.));
+    app.release(MouseButton::Left);
+    app.context().scroll_offset(view).unwrap().1
+}
+
+#[test]
+fn thumb_on_top_is_dragged() {
+    let mut app = Headless::new(Thumb::Free, 200, 200);
+    assert!(drag_thumb(&mut app) > 0.);
+}
+
+#[test]
+fn thumb_under_another_component_is_not_dragged() {
+    let mut app = Headless::new(Thumb::Covered, 200, 200);
+    let overlay = app.component_at((194., 10.)).unwrap();
+    assert!(matches!(app.component(overlay), Comp::Rect(_)));
+    assert_eq!(drag_thumb(&mut app), 0.);
+}
+
+#[test]
+fn thumb_clipped_away_is_not_dragged() {
+    let mut app = Headless::new(Thumb::Clipped, 200, 200);
+    assert_eq!(app.component_at((194., 10.)), None);
+    assert_eq!(drag_thumb(&mut app), 0.);
+}
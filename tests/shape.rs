@@ -0,0 +1,31 @@
+use hierro2d::{
+    component::{Ellipse, IntoComponent},
+    Application, Headless,
+};
+
+struct App {
+    circle: bool,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        match self.circle {
+            true => Ellipse::circle().size(1., 0.5),
+            false => Ellipse::new().size(1., 0.5),
+        }
+    }
+}
+
+#[test]
+fn circle_fits_inside_its_bounds() {
+    // bounds twice as wide as tall, 200 by 100 pixels
+    let ellipse = Headless::new(App { circle: false }, 200, 200);
+    let circle = Headless::new(App { circle: true }, 200, 200);
+    // both cover the center
+    assert!(ellipse.component_at((100., 50.)).is_some());
+    assert!(circle.component_at((100., 50.)).is_some());
+    // only the ellipse reaches the sides of the bounds
+    assert!(ellipse.component_at((10., 50.)).is_some());
+    assert!(circle.component_at((10., 50.)).is_none());
+    assert!(ellipse.component_at((100., 150.)).is_none());
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use hierro2d::{
+    component::{IntoComponent, Text},
+    Application, Clipboard, Headless, MemoryClipboard,
+};
+use winit::event::MouseButton;
+
+const CONTENT: &str = "hello brave world";
+
+struct App;
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        Text::new().content(CONTENT).size(1., 1.).selectable(true)
+    }
+
+    fn clipboard(&self) -> Box<dyn Clipboard> {
+        Box::new(MemoryClipboard::default())
+    }
+}
+
+fn app() -> Headless {
+    Headless::new(App, 600, 100)
+}
+
+fn drag(app: &mut Headless, from: (f32, f32), to: (f32, f32)) {
+    app.move_to(from);
+    app.press(MouseButton::Left);
+    app.move_to(to);
+    app.release(MouseButton::Left);
+}
+
+fn copied(app: &mut Headless) -> Option<String> {
+    let mut context = app.context();
+    context.copy().then(|| context.clipboard().get().unwrap())
+}
+
+#[test]
+fn nothing_to_copy_before_selecting() {
+    let mut app = app();
+    app.click((1., 20.));
+    assert_eq!(copied(&mut app), None);
+    assert_eq!(app.context().clipboard().get(), None);
+}
+
+#[test]
+fn drag_selects_and_copies() {
+    let mut app = app();
+    drag(&mut app, (1., 20.), (599., 20.));
+    assert!(app.focused().is_some());
+    assert_eq!(copied(&mut app).as_deref(), Some(CONTENT));
+
+    drag(&mut app, (1., 20.), (60., 20.));
+    let part = copied(&mut app).unwrap();
+    assert!(!part.is_empty() && part.len() < CONTENT.len(), "{part}");
+    assert!(CONTENT.starts_with(&part), "{part}");
+}
+
+#[test]
+fn double_click_selects_a_word() {
+    let mut app = app();
+    app.click((1., 20.));
+    app.click((1., 20.));
+    assert_eq!(copied(&mut app).as_deref(), Some("hello"));
+
+    // every double click along the line selects one of its words
+    let mut words = BTreeSet::new();
+    for x in (1..400).step_by(5) {
+        app.click((x as f32, 20.));
+        app.click((x as f32, 20.));
+        if let Some(word) = copied(&mut app) {
+            words.insert(word);
+        }
+    }
+    assert_eq!(
+        words,
+        BTreeSet::from(["brave", "hello", "world"].map(String::from))
+    );
+}
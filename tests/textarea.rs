@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use hierro2d::{
+    component::{Camera, Comp, Container, IntoComponent, TextArea},
+    Application, Clipboard, Clock, Headless, ManualClock, MemoryClipboard,
+};
+use winit::event::{Ime, MouseScrollDelta};
+
+struct App {
+    clock: ManualClock,
+}
+
+fn lines() -> TextArea {
+    let content: Vec<String> = (0..40).map(|i| format!("line{i}")).collect();
+    TextArea::new().content(content.join("\n")).size(1., 1.)
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        lines()
+    }
+
+    fn clipboard(&self) -> Box<dyn Clipboard> {
+        Box::new(MemoryClipboard::default())
+    }
+
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(self.clock.clone())
+    }
+}
+
+fn app() -> (Headless, ManualClock) {
+    let clock = ManualClock::new();
+    let app = Headless::new(
+        App {
+            clock: clock.clone(),
+        },
+        200,
+        100,
+    );
+    (app, clock)
+}
+
+fn value(app: &Headless, id: isize) -> String {
+    match app.component(id) {
+        Comp::TextArea(area) => area.value(),
+        _ => unreachable!(),
+    }
+}
+
+/// the word double-clicked at `position`
+fn word_at(app: &mut Headless, clock: &ManualClock, position: (f32, f32)) -> String {
+    // far enough from the last click to start a new double click
+    clock.advance(Duration::from_secs(1));
+    app.click(position);
+    app.click(position);
+    let mut context = app.context();
+    assert!(context.copy());
+    context.clipboard().get().unwrap()
+}
+
+#[test]
+fn wheel_scrolls_the_buffer() {
+    let (mut app, clock) = app();
+    assert_eq!(word_at(&mut app, &clock, (5., 5.)), "line0");
+
+    app.move_to((5., 5.));
+    app.wheel(MouseScrollDelta::LineDelta(0., -3.));
+    let word = word_at(&mut app, &clock, (5., 5.));
+    assert_ne!(word, "line0");
+
+    // scrolling back up stops at the first line
+    app.wheel(MouseScrollDelta::LineDelta(0., 100.));
+    assert_eq!(word_at(&mut app, &clock, (5., 5.)), "line0");
+}
+
+struct InCamera {
+    clock: ManualClock,
+}
+
+impl Application for InCamera {
+    fn view(self) -> impl IntoComponent {
+        Camera::new().size(1., 1.).with_child(lines())
+    }
+
+    fn clipboard(&self) -> Box<dyn Clipboard> {
+        Box::new(MemoryClipboard::default())
+    }
+
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(self.clock.clone())
+    }
+}
+
+#[test]
+fn wheel_scrolls_a_text_area_inside_a_camera() {
+    let clock = ManualClock::new();
+    let mut app = Headless::new(
+        InCamera {
+            clock: clock.clone(),
+        },
+        200,
+        100,
+    );
+    let camera = (0..)
+        .find(|id| matches!(app.component(*id), Comp::Camera(_)))
+        .unwrap();
+
+    app.move_to((5., 5.));
+    app.wheel(MouseScrollDelta::LineDelta(0., -3.));
+    assert_eq!(app.context().camera_zoom(camera), Some(1.));
+    assert_ne!(word_at(&mut app, &clock, (5., 5.)), "line0");
+}
+
+#[test]
+fn preedit_is_shown_but_not_committed() {
+    let (mut app, _) = app();
+    app.click((5., 5.));
+    let id = app.focused().unwrap();
+    let original = value(&app, id);
+
+    app.ime(Ime::Enabled);
+    app.ime(Ime::Preedit("ni".into(), Some((2, 2))));
+    assert_eq!(value(&app, id), original);
+    app.ime(Ime::Preedit("nih".into(), Some((3, 3))));
+    assert_eq!(value(&app, id), original);
+
+    app.ime(Ime::Commit("你好".into()));
+    let value = value(&app, id);
+    assert_ne!(value, original);
+    assert_eq!(value.replacen("你好", "", 1), original);
+}
@@ -0,0 +1,210 @@
+use std::{cell::RefCell, rc::Rc};
+
+use hierro2d::{
+    component::{Component, IntoComponent, Rect},
+    Application, Context, GestureEvent, GestureKind, GestureState, Headless, PointerEvent,
+    PointerKind,
+};
+use winit::event::TouchPhase;
+
+#[derive(Default)]
+struct Log {
+    gestures: Vec<GestureEvent>,
+    pointer: Vec<PointerKind>,
+    /// downs and ups with their positions
+    presses: Vec<(PointerKind, (f32, f32))>,
+}
+
+type Shared = Rc<RefCell<Log>>;
+
+struct App {
+    log: Shared,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        let log = self.log;
+        let (pinch, pan, rotate, click) = (log.clone(), log.clone(), log.clone(), log.clone());
+        let (down, up) = (log.clone(), log);
+        let press = |log: Shared| {
+            move |_: Context, event: &mut PointerEvent| {
+                log.borrow_mut().presses.push((event.kind, event.position))
+            }
+        };
+        Rect::new()
+            .on_mouse_down(press(down))
+            .on_mouse_up(press(up))
+            .on_pinch(move |_, event| pinch.borrow_mut().gestures.push(event.clone()))
+            .on_pan(move |_, event| pan.borrow_mut().gestures.push(event.clone()))
+            .on_rotate(move |_, event| rotate.borrow_mut().gestures.push(event.clone()))
+            .on_click(move |_, event| click.borrow_mut().pointer.push(event.kind))
+    }
+}
+
+fn app() -> (Headless, Shared) {
+    let log = Shared::default();
+    let app = Headless::new(App { log: log.clone() }, 200, 100);
+    (app, log)
+}
+
+/// puts two fingers down on the same line, `distance` apart around (100, 50)
+fn two_fingers(app: &mut Headless, distance: f32) {
+    app.touch(1, TouchPhase::Started, (100. - distance / 2., 50.));
+    app.touch(2, TouchPhase::Started, (100. + distance / 2., 50.));
+}
+
+fn kinds(log: &Shared) -> Vec<(GestureKind, GestureState)> {
+    let log = log.borrow();
+    log.gestures
+        .iter()
+        .map(|event| (event.kind, event.state))
+        .collect()
+}
+
+#[test]
+fn pinch_starts_past_its_threshold() {
+    let (mut app, log) = app();
+    two_fingers(&mut app, 40.);
+    // 2.5% further apart
+    app.touch(2, TouchPhase::Moved, (121., 50.));
+    assert!(kinds(&log).is_empty());
+    // 7.5% further apart
+    app.touch(2, TouchPhase::Moved, (123., 50.));
+    assert_eq!(kinds(&log), [(GestureKind::Pinch, GestureState::Began)]);
+    let scale = log.borrow().gestures[0].scale;
+    assert!((scale - 43. / 40.).abs() < 1e-5, "{scale}");
+
+    app.touch(2, TouchPhase::Moved, (126., 50.));
+    let scale = log.borrow().gestures[1].scale;
+    assert!((scale - 46. / 43.).abs() < 1e-5, "{scale}");
+
+    app.touch(2, TouchPhase::Ended, (126., 50.));
+    assert_eq!(
+        kinds(&log),
+        [
+            (GestureKind::Pinch, GestureState::Began),
+            (GestureKind::Pinch, GestureState::Changed),
+            (GestureKind::Pinch, GestureState::Ended),
+        ]
+    );
+}
+
+/// moves both fingers in small steps, one after the other, along `path` from 0 to 1
+fn move_both(app: &mut Headless, path: impl Fn(f32) -> ((f32, f32), (f32, f32)), steps: u32) {
+    for step in 1..=steps {
+        let (a, b) = path(step as f32 / steps as f32);
+        app.touch(1, TouchPhase::Moved, a);
+        app.touch(2, TouchPhase::Moved, b);
+    }
+}
+
+#[test]
+fn pan_starts_past_its_threshold() {
+    let (mut app, log) = app();
+    two_fingers(&mut app, 40.);
+    let shifted = |from: f32, by: f32| {
+        move |t: f32| ((80. + from + by * t, 50.), (120. + from + by * t, 50.))
+    };
+    move_both(&mut app, shifted(0., 9.), 9);
+    assert!(kinds(&log).is_empty());
+    move_both(&mut app, shifted(9., 3.), 3);
+    assert_eq!(kinds(&log)[0], (GestureKind::Pan, GestureState::Began));
+    let total: f32 = log
+        .borrow()
+        .gestures
+        .iter()
+        .map(|event| event.translation.0)
+        .sum();
+    assert!((total - 12.).abs() < 1e-4, "{total}");
+    assert!(kinds(&log)
+        .iter()
+        .all(|(kind, _)| *kind == GestureKind::Pan));
+}
+
+#[test]
+fn rotate_starts_past_its_threshold() {
+    let (mut app, log) = app();
+    two_fingers(&mut app, 40.);
+    let turned = |angle: f32| {
+        let (dx, dy) = (20. * angle.cos(), 20. * angle.sin());
+        ((100. - dx, 50. - dy), (100. + dx, 50. + dy))
+    };
+    move_both(&mut app, |t| turned(0.09 * t), 9);
+    assert!(kinds(&log).is_empty());
+    move_both(&mut app, |t| turned(0.09 + 0.03 * t), 3);
+    assert_eq!(kinds(&log)[0], (GestureKind::Rotate, GestureState::Began));
+    assert!(kinds(&log)
+        .iter()
+        .all(|(kind, _)| *kind == GestureKind::Rotate));
+    let total: f32 = log
+        .borrow()
+        .gestures
+        .iter()
+        .map(|event| event.rotation)
+        .sum();
+    assert!((total - 0.12).abs() < 1e-4, "{total}");
+}
+
+#[test]
+fn one_finger_taps_like_the_mouse() {
+    let (mut app, log) = app();
+    app.touch(1, TouchPhase::Started, (50., 50.));
+    app.touch(1, TouchPhase::Ended, (50., 50.));
+    assert_eq!(log.borrow().pointer, [PointerKind::Click]);
+}
+
+#[test]
+fn second_finger_cancels_the_press() {
+    let (mut app, log) = app();
+    two_fingers(&mut app, 40.);
+    app.touch(2, TouchPhase::Ended, (120., 50.));
+    app.touch(1, TouchPhase::Ended, (80., 50.));
+    assert!(log.borrow().pointer.is_empty());
+}
+
+#[test]
+fn finger_left_after_a_gesture_takes_the_pointer_without_clicking() {
+    let (mut app, log) = app();
+    two_fingers(&mut app, 40.);
+    app.touch(1, TouchPhase::Ended, (80., 50.));
+    // the finger still down presses where it is
+    assert_eq!(
+        log.borrow().presses,
+        [
+            (PointerKind::Down, (80., 50.)),
+            (PointerKind::Down, (120., 50.))
+        ]
+    );
+
+    app.touch(2, TouchPhase::Moved, (130., 60.));
+    app.touch(2, TouchPhase::Ended, (130., 60.));
+    assert_eq!(log.borrow().presses[2], (PointerKind::Up, (130., 60.)));
+    assert!(log.borrow().pointer.is_empty());
+
+    // the next finger to land clicks again
+    app.touch(3, TouchPhase::Started, (50., 50.));
+    app.touch(3, TouchPhase::Ended, (50., 50.));
+    assert_eq!(log.borrow().pointer, [PointerKind::Click]);
+}
+
+#[test]
+fn gesture_follows_the_fingers_that_landed_first() {
+    let (mut app, log) = app();
+    // ids in no particular order
+    app.touch(9, TouchPhase::Started, (80., 50.));
+    app.touch(4, TouchPhase::Started, (120., 50.));
+    app.touch(6, TouchPhase::Started, (100., 90.));
+    app.touch(6, TouchPhase::Moved, (100., 20.));
+    assert!(kinds(&log).is_empty());
+    app.touch(4, TouchPhase::Moved, (130., 50.));
+    assert_eq!(kinds(&log), [(GestureKind::Pinch, GestureState::Began)]);
+
+    // lifting one of them hands the gesture to the next finger in line
+    app.touch(9, TouchPhase::Ended, (80., 50.));
+    log.borrow_mut().gestures.clear();
+    // away from the other finger, along the line between them
+    app.touch(6, TouchPhase::Moved, (95., 15.));
+    assert_eq!(kinds(&log), [(GestureKind::Pinch, GestureState::Began)]);
+    let position = log.borrow().gestures[0].position;
+    assert_eq!(position, (112.5, 32.5));
+}
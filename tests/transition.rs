@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use hierro2d::{
+    component::{Comp, Component, Container, Ellipse, IntoComponent, Rect},
+    Application, Clock, Easing, Headless, ManualClock, Property,
+};
+
+const DURATION: Duration = Duration::from_millis(100);
+
+/// a window filling rect holding a square at `x` tinted by `shade`, declaring transitions
+fn scene(shade: f32, x: f32) -> Rect {
+    Rect::new().size(1., 1.).with_child(
+        Rect::new()
+            .position(x, 0.)
+            .size(0.5, 0.5)
+            .color(shade, shade, shade, 1.)
+            .transition(Property::Color, DURATION, Easing::Linear)
+            .transition(Property::Position, DURATION, Easing::Linear),
+    )
+}
+
+struct App {
+    clock: ManualClock,
+}
+
+impl Application for App {
+    fn view(self) -> impl IntoComponent {
+        scene(0., 0.)
+    }
+
+    fn clock(&self) -> Box<dyn Clock> {
+        Box::new(self.clock.clone())
+    }
+}
+
+fn app() -> (Headless, ManualClock) {
+    let clock = ManualClock::new();
+    let app = Headless::new(
+        App {
+            clock: clock.clone(),
+        },
+        200,
+        100,
+    );
+    (app, clock)
+}
+
+/// the square, the only component that is not the root
+fn square(app: &Headless) -> isize {
+    let root = app.component_at((190., 90.)).unwrap();
+    (0..2).find(|id| *id != root).unwrap()
+}
+
+fn red(app: &Headless, id: isize) -> f32 {
+    app.component(id).get_color().unwrap().0
+}
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn setter_change_passes_through_intermediate_values() {
+    let (mut app, clock) = app();
+    let id = square(&app);
+    app.context().set_color(id, 1., 1., 1., 1.);
+    app.update();
+    assert_eq!(red(&app, id), 0.);
+
+    clock.advance(DURATION / 4);
+    app.update();
+    assert!(close(red(&app, id), 0.25), "{}", red(&app, id));
+
+    clock.advance(DURATION);
+    app.update();
+    assert_eq!(red(&app, id), 1.);
+    assert!(!app.animating());
+}
+
+#[test]
+fn new_view_transitions_from_the_old_values() {
+    let (mut app, clock) = app();
+    let old = app.component(square(&app)).get_position();
+    app.context().set_view(scene(1., 0.5));
+    app.update();
+    let id = square(&app);
+    assert_eq!(red(&app, id), 0.);
+    assert_eq!(app.component(id).get_position(), old);
+
+    clock.advance(DURATION / 2);
+    app.update();
+    assert!(close(red(&app, id), 0.5), "{}", red(&app, id));
+    // halfway from the left edge to the middle of the window
+    let (x, _) = app.component(id).get_position();
+    assert!(close(x, -0.5), "{x}");
+
+    clock.advance(DURATION);
+    app.update();
+    assert_eq!(red(&app, id), 1.);
+    assert!(close(app.component(id).get_position().0, 0.));
+    assert!(!app.animating());
+}
+
+#[test]
+fn components_of_another_kind_start_at_their_new_values() {
+    let (mut app, _) = app();
+    app.context().set_view(
+        Rect::new().size(1., 1.).with_child(
+            Ellipse::new()
+                .size(0.5, 0.5)
+                .color(1., 1., 1., 1.)
+                .transition(Property::Color, DURATION, Easing::Linear),
+        ),
+    );
+    app.update();
+    let id = (0..2)
+        .find(|id| matches!(app.component(*id), Comp::Ellipse(_)))
+        .unwrap();
+    assert_eq!(red(&app, id), 1.);
+    assert!(!app.animating());
+}